@@ -24,12 +24,35 @@
 //!
 //! This generates `MessageScrollManager` for the appropriate platform
 //! (UniFFI when `uniffi` feature enabled, WASM when `wasm` feature enabled).
+//!
+//! # UniFFI subscriptions
+//!
+//! `subscribe` on the visible set, diff and debug info signals returns a
+//! `{Model}ScrollSubscription`. Callbacks stop once it is unsubscribed or dropped, so hold
+//! it for the lifetime of the component that renders the list (this runs as
+//! `test_visible_set_subscription` in the repository's `uniffi-bindings` crate):
+//!
+//! ```ignore
+//! let calls = Arc::new(AtomicUsize::new(0));
+//! let subscription = manager.clone().visible_set().subscribe(Box::new(Counter(calls.clone())));
+//! assert_eq!(calls.load(SeqCst), 1); // current value delivered immediately
+//!
+//! manager.clone().on_scroll(first, last, true); // slides, emitting new visible sets
+//! let before = calls.load(SeqCst);
+//! assert!(before > 1);
+//!
+//! subscription.unsubscribe(); // or drop(subscription)
+//! assert!(!subscription.is_active());
+//! manager.clone().on_scroll(first, last, true);
+//! assert_eq!(calls.load(SeqCst), before); // no callbacks after unsubscribing
+//! ```
 
+mod methods;
 mod uniffi;
 mod wasm;
 
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, quote_spanned};
 use syn::{parse::{Parse, ParseStream}, parse_macro_input, Ident, LitStr, Path, Token};
 
 /// Configuration parsed from generate_scroll_manager! macro arguments
@@ -37,7 +60,13 @@ struct ScrollManagerConfig {
     model_path: Path,
     view_path: Path,
     livequery_path: Path,
-    timestamp_field: String,
+    timestamp_field: LitStr,
+    default_order: Option<LitStr>,
+    default_predicate: Option<LitStr>,
+    min_row_height: Option<u32>,
+    buffer_factor: Option<f64>,
+    live_edge: LiveEdge,
+    name: Option<Ident>,
 }
 
 impl ScrollManagerConfig {
@@ -45,11 +74,40 @@ impl ScrollManagerConfig {
     fn model_name(&self) -> &Ident {
         &self.model_path.segments.last().unwrap().ident
     }
+
+    fn defaults(&self) -> Defaults {
+        let timestamp_field = self.timestamp_field.value();
+        Defaults {
+            order_by: self
+                .default_order
+                .as_ref()
+                .map_or_else(|| format!("{} DESC", timestamp_field), LitStr::value),
+            predicate: self.default_predicate.as_ref().map_or_else(|| "true".to_string(), LitStr::value),
+            min_row_height: self.min_row_height,
+            buffer_factor: self.buffer_factor,
+            live_edge: self.live_edge,
+            timestamp_field,
+        }
+    }
 }
 
+/// The companion type prefix of a manager name: `Chat` for `ChatScrollManager`
+pub(crate) fn model_prefix(scroll_manager_name: &Ident) -> syn::Result<String> {
+    match scroll_manager_name.to_string().strip_suffix("ScrollManager") {
+        Some(prefix) if !prefix.is_empty() => Ok(prefix.to_string()),
+        _ => Err(syn::Error::new(
+            scroll_manager_name.span(),
+            "name must end in `ScrollManager` after a non-empty prefix (e.g. `ChatScrollManager`)",
+        )),
+    }
+}
+
+const OPTION_KEYS: &str =
+    "timestamp_field, default_order, default_predicate, min_row_height, buffer_factor, live_edge, name";
+
 impl Parse for ScrollManagerConfig {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        // Parse: Model, View, LiveQuery, timestamp_field = "field"
+        // Parse: Model, View, LiveQuery, timestamp_field = "field"[, key = value]*
         // Paths can be simple (Message) or qualified (my_crate::Message)
         let model_path: Path = input.parse()?;
         input.parse::<Token![,]>()?;
@@ -60,7 +118,7 @@ impl Parse for ScrollManagerConfig {
         let livequery_path: Path = input.parse()?;
         input.parse::<Token![,]>()?;
 
-        // Parse timestamp_field = "value"
+        // timestamp_field comes first; the optional keys follow in any order
         let key: Ident = input.parse()?;
         if key != "timestamp_field" {
             return Err(syn::Error::new(key.span(), "expected `timestamp_field`"));
@@ -68,12 +126,115 @@ impl Parse for ScrollManagerConfig {
         input.parse::<Token![=]>()?;
         let timestamp_field: LitStr = input.parse()?;
 
-        Ok(Self {
+        let mut config = Self {
             model_path,
             view_path,
             livequery_path,
-            timestamp_field: timestamp_field.value(),
-        })
+            timestamp_field,
+            default_order: None,
+            default_predicate: None,
+            min_row_height: None,
+            buffer_factor: None,
+            live_edge: LiveEdge::Bottom,
+            name: None,
+        };
+        let mut seen: Vec<String> = vec!["timestamp_field".to_string()];
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if seen.contains(&key.to_string()) {
+                return Err(syn::Error::new(key.span(), format!("duplicate option `{}`", key)));
+            }
+            seen.push(key.to_string());
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "default_order" => {
+                    let order: LitStr = input.parse()?;
+                    if order.value().trim().is_empty() {
+                        return Err(syn::Error::new(order.span(), "default_order must not be empty"));
+                    }
+                    config.default_order = Some(order);
+                }
+                "default_predicate" => config.default_predicate = Some(input.parse()?),
+                "min_row_height" => {
+                    let height: syn::LitInt = input.parse()?;
+                    match height.base10_parse::<u32>()? {
+                        0 => return Err(syn::Error::new(height.span(), "min_row_height must be positive")),
+                        value => config.min_row_height = Some(value),
+                    }
+                }
+                "buffer_factor" => {
+                    let lit: syn::Lit = input.parse()?;
+                    let value = match &lit {
+                        syn::Lit::Float(f) => f.base10_parse::<f64>()?,
+                        syn::Lit::Int(i) => i.base10_parse::<f64>()?,
+                        _ => return Err(syn::Error::new(lit.span(), "buffer_factor must be a number")),
+                    };
+                    if value <= 0.0 {
+                        return Err(syn::Error::new(lit.span(), "buffer_factor must be positive"));
+                    }
+                    config.buffer_factor = Some(value);
+                }
+                "live_edge" => {
+                    let edge: Ident = input.parse()?;
+                    config.live_edge = match edge.to_string().as_str() {
+                        "bottom" => LiveEdge::Bottom,
+                        "top" => LiveEdge::Top,
+                        _ => return Err(syn::Error::new(edge.span(), "live_edge must be `bottom` or `top`")),
+                    };
+                }
+                "name" => {
+                    let name: Ident = input.parse()?;
+                    model_prefix(&name)?;
+                    config.name = Some(name);
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("unknown option `{}` (expected one of: {})", key, OPTION_KEYS),
+                    ))
+                }
+            }
+        }
+        if !input.is_empty() {
+            return Err(input.error("expected `,`"));
+        }
+
+        Ok(config)
+    }
+}
+
+/// Viewport edge where the newest items (the live edge) are shown
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LiveEdge {
+    /// Chat-style: newest at the bottom
+    Bottom,
+    /// Feed-style: newest at the top (the renderer inverts the oldest-first items)
+    Top,
+}
+
+/// Defaults baked into the generated constructors
+pub(crate) struct Defaults {
+    pub timestamp_field: String,
+    /// `default_order`, else `"{timestamp_field} DESC"`
+    pub order_by: String,
+    /// `default_predicate`, else `"true"`
+    pub predicate: String,
+    pub min_row_height: Option<u32>,
+    pub buffer_factor: Option<f64>,
+    pub live_edge: LiveEdge,
+}
+
+impl Defaults {
+    /// Defaults when only `timestamp_field` is given (as for `#[derive(VirtualScroll)]`)
+    fn for_timestamp_field(timestamp_field: String) -> Self {
+        Self {
+            order_by: format!("{} DESC", timestamp_field),
+            predicate: "true".to_string(),
+            min_row_height: None,
+            buffer_factor: None,
+            live_edge: LiveEdge::Bottom,
+            timestamp_field,
+        }
     }
 }
 
@@ -84,12 +245,33 @@ impl Parse for ScrollManagerConfig {
 /// - Model type name (e.g., `Message`)
 /// - View type name (e.g., `MessageView`)
 /// - LiveQuery type name (e.g., `MessageLiveQuery`)
-/// - `timestamp_field = "field_name"` - The timestamp field used for pagination
+/// - `timestamp_field = "field_name"` - The timestamp field used for pagination: the
+///   default display order is `"{field} DESC"` and `start_at` values refer to it. Checked
+///   at compile time to name an `i64` property of the view: the generated `start_at` /
+///   `startAt` options are `i64`, so `u64`, string and other orderable keys aren't
+///   supported here. Models keyed that way can use `ScrollManager::builder`, whose
+///   `start_at` takes any `Into<Value>`.
+///
+/// Optional keys, in any order after `timestamp_field`:
+///
+/// - `default_order = "priority DESC, timestamp DESC"` - ORDER BY used when none is given
+/// - `default_predicate = "deleted = false"` - filter used when none is given
+/// - `min_row_height = 40` - row height used when none is given
+/// - `buffer_factor = 2.0` - buffer (in screens) used when none is given
+/// - `live_edge = bottom | top` - where the renderer shows the newest items
+/// - `name = ChatScrollManager` - name of the generated manager, so one model can have
+///   several managers (e.g. one per screen); it must end in `ScrollManager`, and the rest
+///   prefixes its companion types (`ChatVisibleSet`, ...)
+///
+/// With `min_row_height` set, the wrappers also get `with_viewport(ctx, viewport_height)`
+/// and `with_predicate(ctx, predicate, viewport_height)` constructors that use the defaults.
 ///
 /// # Generated Types
 ///
 /// For a model named `Message`, this generates:
 /// - `MessageScrollManager` - Platform-specific scroll manager wrapper
+/// - `MessageVisibleSet`, `MessageScrollMode`, ... - Companion types, prefixed with the
+///   manager name less its `ScrollManager` suffix
 ///
 /// The scroll manager wraps `ankurah_virtual_scroll::ScrollManager` and integrates with
 /// the model's `LiveQuery` type for reactive pagination.
@@ -103,18 +285,59 @@ pub fn generate_scroll_manager(input: TokenStream) -> TokenStream {
     let config = parse_macro_input!(input as ScrollManagerConfig);
 
     let model_name = config.model_name();
-    let scroll_manager_name = format_ident!("{}ScrollManager", model_name);
+    let scroll_manager_name = match &config.name {
+        Some(name) => name.clone(),
+        None => format_ident!("{}ScrollManager", model_name),
+    };
     let view_path = &config.view_path;
     let livequery_path = &config.livequery_path;
-    let timestamp_field = &config.timestamp_field;
+    let defaults = config.defaults();
+
+    // The field must name an i64 property of the model, which the view exposes as an
+    // accessor. A missing accessor resolves to the fallback trait's method instead, so
+    // both cases report the message below at the `timestamp_field` literal.
+    let field_check = match field_ident(&config.timestamp_field) {
+        Ok(field) => {
+            let view_name = &view_path.segments.last().unwrap().ident;
+            let message = format!("`{}` is not an `i64` property of `{}`", field, view_name);
+            let check = quote_spanned!(field.span()=> __check(view.#field()));
+            quote! {
+                const _: () = {
+                    #[diagnostic::on_unimplemented(
+                        message = #message,
+                        label = "timestamp_field must name an i64 property (start_at values are i64)"
+                    )]
+                    trait __TimestampField {}
+                    impl __TimestampField for i64 {}
+
+                    struct __MissingField;
+                    trait __Fallback {
+                        fn #field(&self) -> ::std::result::Result<__MissingField, ()> {
+                            Ok(__MissingField)
+                        }
+                    }
+                    impl<T: ?Sized> __Fallback for T {}
+
+                    fn __check<T: __TimestampField, E>(_: ::std::result::Result<T, E>) {}
+
+                    #[allow(dead_code)]
+                    fn __check_timestamp_field(view: &#view_path) {
+                        #check;
+                    }
+                };
+            }
+        }
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     // Generate UniFFI implementation
-    let uniffi_impl = uniffi::generate_with_paths(&scroll_manager_name, view_path, livequery_path, timestamp_field);
+    let uniffi_impl = uniffi::generate_with_paths(&scroll_manager_name, view_path, livequery_path, &defaults);
 
     // Generate WASM implementation
-    let wasm_impl = wasm::generate_with_paths(&scroll_manager_name, view_path, livequery_path, timestamp_field);
+    let wasm_impl = wasm::generate_with_paths(&scroll_manager_name, view_path, livequery_path, &defaults);
 
     let expanded = quote! {
+        #field_check
         #uniffi_impl
         #wasm_impl
     };
@@ -135,6 +358,10 @@ pub fn derive_virtual_scroll(input: TokenStream) -> TokenStream {
         Ok(field) => field,
         Err(e) => return e.to_compile_error().into(),
     };
+    if let Err(e) = check_struct_field(&input, &timestamp_field) {
+        return e.to_compile_error().into();
+    }
+    let defaults = Defaults::for_timestamp_field(timestamp_field.value());
 
     let model_name = &input.ident;
     let scroll_manager_name = format_ident!("{}ScrollManager", model_name);
@@ -142,10 +369,10 @@ pub fn derive_virtual_scroll(input: TokenStream) -> TokenStream {
     let livequery_name = format_ident!("{}LiveQuery", model_name);
 
     // Generate UniFFI implementation
-    let uniffi_impl = uniffi::generate(&scroll_manager_name, &view_name, &livequery_name, &timestamp_field);
+    let uniffi_impl = uniffi::generate(&scroll_manager_name, &view_name, &livequery_name, &defaults);
 
     // Generate WASM implementation
-    let wasm_impl = wasm::generate(&scroll_manager_name, &view_name, &livequery_name, &timestamp_field);
+    let wasm_impl = wasm::generate(&scroll_manager_name, &view_name, &livequery_name, &defaults);
 
     let hygiene_module = format_ident!("__virtual_scroll_impl_{}", to_snake_case(&model_name.to_string()));
 
@@ -162,14 +389,14 @@ pub fn derive_virtual_scroll(input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
-fn parse_timestamp_field(input: &syn::DeriveInput) -> Result<String, syn::Error> {
+fn parse_timestamp_field(input: &syn::DeriveInput) -> Result<LitStr, syn::Error> {
     for attr in &input.attrs {
         if attr.path().is_ident("virtual_scroll") {
             let mut timestamp_field = None;
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("timestamp_field") {
                     let value: LitStr = meta.value()?.parse()?;
-                    timestamp_field = Some(value.value());
+                    timestamp_field = Some(value);
                     Ok(())
                 } else {
                     Err(meta.error("unknown attribute"))
@@ -187,6 +414,38 @@ fn parse_timestamp_field(input: &syn::DeriveInput) -> Result<String, syn::Error>
     ))
 }
 
+/// Parse a `timestamp_field` value as a field name, reporting errors at the literal
+fn field_ident(field: &LitStr) -> Result<Ident, syn::Error> {
+    syn::parse_str::<Ident>(&field.value())
+        .map(|ident| Ident::new(&ident.to_string(), field.span()))
+        .map_err(|_| syn::Error::new(field.span(), format!("`{}` is not a valid field name", field.value())))
+}
+
+/// Check that `timestamp_field` names one of the struct's fields, of type `i64` (the
+/// generated `start_at` options are i64)
+fn check_struct_field(input: &syn::DeriveInput, field: &LitStr) -> Result<(), syn::Error> {
+    let ident = field_ident(field)?;
+    let syn::Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(input, "VirtualScroll can only be derived for structs"));
+    };
+    if let Some(found) = data.fields.iter().find(|f| f.ident.as_ref() == Some(&ident)) {
+        let is_i64 = matches!(&found.ty, syn::Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident("i64"));
+        if is_i64 {
+            return Ok(());
+        }
+        let ty = &found.ty;
+        return Err(syn::Error::new(
+            field.span(),
+            format!("timestamp field `{}` has type `{}`; it must be `i64`", ident, quote!(#ty)),
+        ));
+    }
+    let names: Vec<String> = data.fields.iter().filter_map(|f| f.ident.as_ref().map(|i| i.to_string())).collect();
+    Err(syn::Error::new(
+        field.span(),
+        format!("no field `{}` on `{}` (fields: {})", ident, input.ident, names.join(", ")),
+    ))
+}
+
 /// Convert PascalCase to snake_case
 fn to_snake_case(s: &str) -> String {
     s.chars()
@@ -0,0 +1,258 @@
+//! Shared description of the ScrollManager operations forwarded by the wrappers
+//!
+//! `uniffi.rs` and `wasm.rs` both render every entry of [`METHODS`], so an operation added
+//! here appears on both platforms with the same name, arguments and docs. Only the type
+//! mapping is per platform. Constructors and the signal accessors (`visible_set`,
+//! `visible_set_diff`, `debug_info`) return platform-specific wrappers and are written out
+//! in each generator.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// A wrapper method forwarding to `ScrollManager`
+pub(crate) struct Method {
+    /// Rust (and UniFFI) name
+    pub name: &'static str,
+    /// JavaScript name
+    pub js_name: &'static str,
+    /// `ScrollManager` method called
+    pub core: &'static str,
+    /// Doc comment lines
+    pub docs: &'static [&'static str],
+    pub params: &'static [Param],
+    pub returns: Returns,
+    pub is_async: bool,
+    /// Exposed as a property in JavaScript
+    pub getter: bool,
+}
+
+/// A method argument
+pub(crate) struct Param {
+    pub name: &'static str,
+    pub ty: ParamType,
+}
+
+/// Argument types, as the host passes them
+#[derive(Clone, Copy)]
+pub(crate) enum ParamType {
+    /// EntityId as its base64 string
+    EntityId,
+    Bool,
+    U32,
+    F64,
+    /// Duration in milliseconds
+    Millis,
+    /// AnkQL predicate string
+    Predicate,
+}
+
+/// Return types, converted from the core's
+#[derive(Clone, Copy)]
+pub(crate) enum Returns {
+    Unit,
+    Bool,
+    String,
+    /// `ScrollMode`, as the generated `{Prefix}ScrollMode`
+    Mode,
+    /// `Result<(), ScrollError>`, as the platform's error type
+    Result,
+}
+
+const SCROLL_PARAMS: &[Param] = &[
+    Param { name: "first_visible", ty: ParamType::EntityId },
+    Param { name: "last_visible", ty: ParamType::EntityId },
+    Param { name: "scrolling_backward", ty: ParamType::Bool },
+];
+
+const SCROLL_VELOCITY_PARAMS: &[Param] = &[
+    Param { name: "first_visible", ty: ParamType::EntityId },
+    Param { name: "last_visible", ty: ParamType::EntityId },
+    Param { name: "scrolling_backward", ty: ParamType::Bool },
+    Param { name: "velocity", ty: ParamType::F64 },
+];
+
+pub(crate) const METHODS: &[Method] = &[
+    Method {
+        name: "start",
+        js_name: "start",
+        core: "start",
+        docs: &["Initialize the scroll manager and populate initial items", "", "Must be called after construction."],
+        params: &[],
+        returns: Returns::Unit,
+        is_async: true,
+        getter: false,
+    },
+    Method {
+        name: "start_with_timeout",
+        js_name: "startWithTimeout",
+        core: "start_with_timeout",
+        docs: &[
+            "Initialize the scroll manager, failing with a timeout ScrollError if the initial",
+            "result takes longer than `timeout_ms` (the manager stays usable and may be restarted)",
+        ],
+        params: &[Param { name: "timeout_ms", ty: ParamType::Millis }],
+        returns: Returns::Result,
+        is_async: true,
+        getter: false,
+    },
+    Method {
+        name: "dispose",
+        js_name: "dispose",
+        core: "close",
+        docs: &[
+            "Unsubscribe from the livequery and cancel pending work",
+            "",
+            "Call when the list unmounts; the wrapper itself is still released by `destroy()`",
+            "(UniFFI) or `free()` (JS).",
+        ],
+        params: &[],
+        returns: Returns::Unit,
+        is_async: false,
+        getter: false,
+    },
+    Method {
+        name: "is_disposed",
+        js_name: "isDisposed",
+        core: "is_closed",
+        docs: &["Whether `dispose()` has been called"],
+        params: &[],
+        returns: Returns::Bool,
+        is_async: false,
+        getter: true,
+    },
+    Method {
+        name: "on_scroll",
+        js_name: "onScroll",
+        core: "on_scroll",
+        docs: &[
+            "Process a scroll event",
+            "",
+            "# Arguments",
+            "* `first_visible` - EntityId of the first (oldest) visible item",
+            "* `last_visible` - EntityId of the last (newest) visible item",
+            "* `scrolling_backward` - True if user is scrolling toward older items",
+        ],
+        params: SCROLL_PARAMS,
+        returns: Returns::Unit,
+        is_async: false,
+        getter: false,
+    },
+    Method {
+        name: "on_scroll_with_velocity",
+        js_name: "onScrollWithVelocity",
+        core: "on_scroll_with_velocity",
+        docs: &[
+            "Process a scroll event with the current scroll speed (pixels per second)",
+            "",
+            "Fast flings trigger pagination earlier and fetch further ahead.",
+        ],
+        params: SCROLL_VELOCITY_PARAMS,
+        returns: Returns::Unit,
+        is_async: false,
+        getter: false,
+    },
+    Method {
+        name: "jump_to_live",
+        js_name: "jumpToLive",
+        core: "jump_to_live",
+        docs: &[
+            "Return to the live edge (e.g. from a \"Jump to latest\" button)",
+            "",
+            "Supersedes any slide in flight; resolves once the live window has been emitted.",
+        ],
+        params: &[],
+        returns: Returns::Result,
+        is_async: true,
+        getter: false,
+    },
+    Method {
+        name: "set_viewport_height",
+        js_name: "setViewportHeight",
+        core: "set_viewport_height",
+        docs: &[
+            "Resize the viewport (e.g. on rotation or window resize)",
+            "",
+            "In Live mode the live window is reloaded at its new size; mid-history the next",
+            "slide picks it up.",
+        ],
+        params: &[Param { name: "viewport_height", ty: ParamType::U32 }],
+        returns: Returns::Result,
+        is_async: true,
+        getter: false,
+    },
+    Method {
+        name: "set_predicate",
+        js_name: "setPredicate",
+        core: "set_predicate",
+        docs: &[
+            "Replace the base filter (e.g. switching rooms) and reload the live window",
+            "",
+            "Resolves once the new window has been emitted.",
+        ],
+        params: &[Param { name: "predicate", ty: ParamType::Predicate }],
+        returns: Returns::Result,
+        is_async: true,
+        getter: false,
+    },
+    Method {
+        name: "mode",
+        js_name: "mode",
+        core: "mode",
+        docs: &["Get the current scroll mode"],
+        params: &[],
+        returns: Returns::Mode,
+        is_async: false,
+        getter: true,
+    },
+    Method {
+        name: "current_selection",
+        js_name: "currentSelection",
+        core: "current_selection",
+        docs: &["Get the current selection (predicate + order by) as a string"],
+        params: &[],
+        returns: Returns::String,
+        is_async: false,
+        getter: false,
+    },
+];
+
+impl Method {
+    pub fn ident(&self) -> syn::Ident {
+        format_ident!("{}", self.name)
+    }
+
+    pub fn doc_attrs(&self) -> TokenStream {
+        let lines = self.docs.iter().map(|line| format!(" {}", line));
+        quote! { #(#[doc = #lines])* }
+    }
+
+    /// `manager.core(args)` (awaited for async methods), with each argument converted by
+    /// the platform's `convert`
+    pub fn core_call(&self, manager: TokenStream, convert: impl Fn(&Param) -> TokenStream) -> TokenStream {
+        let core = format_ident!("{}", self.core);
+        let args = self.params.iter().map(convert);
+        let await_ = if self.is_async { quote!(.await) } else { quote!() };
+        quote! { #manager.#core(#(#args),*)#await_ }
+    }
+}
+
+impl Param {
+    pub fn ident(&self) -> syn::Ident {
+        format_ident!("{}", self.name)
+    }
+}
+
+/// Conversion of a host argument to the core's type, shared by both platforms (they
+/// differ only in the integer type used for milliseconds)
+pub(crate) fn convert_param(param: &Param) -> TokenStream {
+    let ident = param.ident();
+    let invalid = format!("Invalid {} EntityId", param.name);
+    match param.ty {
+        ParamType::EntityId => quote! {
+            #ident.parse::<::ankurah_virtual_scroll::Id>().expect(#invalid)
+        },
+        ParamType::Bool | ParamType::U32 | ParamType::F64 => quote!(#ident),
+        ParamType::Millis => quote!(::std::time::Duration::from_millis(#ident.into())),
+        ParamType::Predicate => quote!(#ident.as_str()),
+    }
+}
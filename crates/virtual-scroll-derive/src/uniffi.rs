@@ -9,17 +9,23 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::{Ident, Path};
 
+use crate::methods::{self, Method, ParamType, Returns};
+use crate::{Defaults, LiveEdge};
+
 /// Generate UniFFI implementation for the scroll manager (with paths)
 pub fn generate_with_paths(
     scroll_manager_name: &Ident,
     view_path: &Path,
     _livequery_path: &Path,
-    timestamp_field: &str,
+    defaults: &Defaults,
 ) -> TokenStream {
-    let model_name = &scroll_manager_name.to_string().replace("ScrollManager", "");
-    let model_ident = Ident::new(model_name, scroll_manager_name.span());
+    let model_name = match crate::model_prefix(scroll_manager_name) {
+        Ok(model_name) => model_name,
+        Err(e) => return e.to_compile_error(),
+    };
+    let model_ident = Ident::new(&model_name, scroll_manager_name.span());
 
-    generate_impl(scroll_manager_name, &model_ident, view_path, timestamp_field)
+    generate_impl(scroll_manager_name, &model_ident, view_path, defaults)
 }
 
 /// Generate UniFFI implementation for the scroll manager (with idents - for backwards compat)
@@ -27,19 +33,22 @@ pub fn generate(
     scroll_manager_name: &Ident,
     view_name: &Ident,
     _livequery_name: &Ident,
-    timestamp_field: &str,
+    defaults: &Defaults,
 ) -> TokenStream {
-    let model_name = &scroll_manager_name.to_string().replace("ScrollManager", "");
-    let model_ident = Ident::new(model_name, scroll_manager_name.span());
+    let model_name = match crate::model_prefix(scroll_manager_name) {
+        Ok(model_name) => model_name,
+        Err(e) => return e.to_compile_error(),
+    };
+    let model_ident = Ident::new(&model_name, scroll_manager_name.span());
 
-    generate_impl(scroll_manager_name, &model_ident, &syn::parse_quote!(#view_name), timestamp_field)
+    generate_impl(scroll_manager_name, &model_ident, &syn::parse_quote!(#view_name), defaults)
 }
 
 fn generate_impl(
     scroll_manager_name: &Ident,
     model_name: &Ident,
     view_path: &Path,
-    _timestamp_field: &str,
+    defaults: &Defaults,
 ) -> TokenStream {
     let visible_set_name = format_ident!("{}VisibleSet", model_name);
     let visible_set_signal_name = format_ident!("{}VisibleSetSignal", model_name);
@@ -48,6 +57,114 @@ fn generate_impl(
     let debug_info_name = format_ident!("{}ScrollDebugInfo", model_name);
     let debug_info_signal_name = format_ident!("{}ScrollDebugInfoSignal", model_name);
     let debug_callback_name = format_ident!("{}ScrollDebugInfoCallback", model_name);
+    let change_name = format_ident!("{}VisibleSetChange", model_name);
+    let diff_name = format_ident!("{}VisibleSetDiff", model_name);
+    let diff_signal_name = format_ident!("{}VisibleSetDiffSignal", model_name);
+    let diff_callback_name = format_ident!("{}VisibleSetDiffCallback", model_name);
+    let options_name = format_ident!("{}ScrollOptions", model_name);
+    let error_name = format_ident!("{}ScrollError", model_name);
+    let error_info_name = format_ident!("{}ScrollErrorInfo", model_name);
+    let failure_name = format_ident!("{}IntersectionFailure", model_name);
+    let recovery_name = format_ident!("{}RecoveryAction", model_name);
+    let default_order_by = &defaults.order_by;
+    let default_predicate = &defaults.predicate;
+    let order_by_doc = format!("ORDER BY clause (default \"{}\")", default_order_by);
+    let predicate_doc = format!("Base filter predicate (default \"{}\")", default_predicate);
+    let start_at_doc =
+        format!("Open the window at this `{}` value instead of the live edge", defaults.timestamp_field);
+    let mode_name = format_ident!("{}ScrollMode", model_name);
+    let subscription_name = format_ident!("{}ScrollSubscription", model_name);
+    let direction_name = format_ident!("{}LoadDirection", model_name);
+    let live_edge_name = format_ident!("{}LiveEdge", model_name);
+    let module_name = format_ident!("__uniffi_{}", crate::to_snake_case(&scroll_manager_name.to_string()));
+
+    // Shared by the thrown error and the plain enum on the visible set
+    let error_variants = quote! {
+        /// A slide's anchor was missing from its result and could not be recovered
+        IntersectionFailed { anchor: String, direction: #direction_name },
+        /// The livequery rejected a slide's selection
+        QueryFailed { message: String },
+        /// The cursor item's ORDER BY value could not be used in a query
+        CursorConversionFailed { message: String },
+        /// The manager was used in a state that doesn't allow the operation
+        InvalidState { message: String },
+        /// start_with_timeout gave up waiting for the initial query result
+        Timeout { millis: u64 },
+        /// The manager was closed, so the operation was cancelled
+        Closed,
+    };
+    let error_from = quote! {
+        use ::ankurah_virtual_scroll::ScrollError;
+        match error {
+            ScrollError::IntersectionFailed { anchor, direction } => Self::IntersectionFailed {
+                anchor: anchor.to_string(),
+                direction: (*direction).into(),
+            },
+            ScrollError::QueryFailed(message) => Self::QueryFailed { message: message.clone() },
+            ScrollError::CursorConversionFailed(message) => Self::CursorConversionFailed { message: message.clone() },
+            ScrollError::InvalidState(message) => Self::InvalidState { message: message.clone() },
+            ScrollError::Timeout(after) => Self::Timeout { millis: after.as_millis() as u64 },
+            ScrollError::Closed => Self::Closed,
+        }
+    };
+
+    let live_edge = match defaults.live_edge {
+        LiveEdge::Bottom => quote!(#live_edge_name::Bottom),
+        LiveEdge::Top => quote!(#live_edge_name::Top),
+    };
+    let default_config = match defaults.buffer_factor {
+        Some(buffer_factor) => quote!(::ankurah_virtual_scroll::ScrollConfig::default().buffer_factor(#buffer_factor)),
+        None => quote!(::ankurah_virtual_scroll::ScrollConfig::default()),
+    };
+    let row_height_field = match defaults.min_row_height {
+        Some(height) => {
+            let doc = format!("Guaranteed minimum item height in pixels (default {})", height);
+            let height = proc_macro2::Literal::u32_unsuffixed(height);
+            quote! {
+                #[doc = #doc]
+                #[uniffi(default = #height)]
+                pub minimum_row_height: u32,
+            }
+        }
+        None => quote! {
+            /// Guaranteed minimum item height in pixels
+            pub minimum_row_height: u32,
+        },
+    };
+    // Constructors that need only the viewport, when the macro supplied a row height
+    let convenience_constructors = match defaults.min_row_height {
+        Some(height) => quote! {
+            /// Create a scroll manager with the default predicate, order and row height
+            #[uniffi::constructor]
+            pub fn with_viewport(
+                ctx: &::ankurah::Context,
+                viewport_height: u32,
+            ) -> Result<Arc<Self>, ::ankurah::error::RetrievalError> {
+                Self::with_predicate(ctx, #default_predicate.to_string(), viewport_height)
+            }
+
+            /// Create a scroll manager with the default order and row height
+            #[uniffi::constructor]
+            pub fn with_predicate(
+                ctx: &::ankurah::Context,
+                predicate: String,
+                viewport_height: u32,
+            ) -> Result<Arc<Self>, ::ankurah::error::RetrievalError> {
+                let manager = ::ankurah_virtual_scroll::ScrollManager::<#view_path>::builder(ctx)
+                    .predicate(predicate.as_str())
+                    .order_by(#default_order_by)
+                    .viewport(viewport_height)
+                    .row_height(#height)
+                    .config(#default_config)
+                    .build()?;
+                Ok(Arc::new(Self(manager)))
+            }
+        },
+        None => quote!(),
+    };
+    let forwarded = methods::METHODS
+        .iter()
+        .map(|method| forward_method(method, &mode_name, &error_name));
 
     quote! {
         // Callback interface for visible_set signal subscription
@@ -64,18 +181,151 @@ fn generate_impl(
             fn on_change(&self, value: ::std::sync::Arc<#debug_info_name>);
         }
 
+        // Callback interface for visible_set_diff signal subscription
+        #[cfg(feature = "uniffi")]
+        #[::uniffi::export(callback_interface)]
+        pub trait #diff_callback_name: Send + Sync {
+            fn on_change(&self, value: ::std::sync::Arc<#diff_name>);
+        }
+
         #[cfg(feature = "uniffi")]
-        mod __uniffi_scroll_manager {
+        mod #module_name {
             use super::*;
             use ::std::sync::Arc;
             use ::ankurah_virtual_scroll::ankurah_signals::{Get, Peek, Subscribe};
 
+            /// Construction options (mirrors `ScrollManager::builder`)
+            ///
+            /// Unset optional fields use the macro defaults (order, predicate, buffer factor),
+            /// then the builder defaults: buffer factor 2.0, default thresholds, starting at
+            /// the live edge.
+            #[derive(::uniffi::Record)]
+            pub struct #options_name {
+                /// Viewport height in pixels
+                pub viewport_height: u32,
+                #row_height_field
+                #[doc = #order_by_doc]
+                #[uniffi(default = None)]
+                pub order_by: Option<String>,
+                #[doc = #predicate_doc]
+                #[uniffi(default = None)]
+                pub predicate: Option<String>,
+                /// Buffer as multiple of viewport
+                #[uniffi(default = None)]
+                pub buffer_factor: Option<f64>,
+                /// Items kept beyond the visible area on each side after a slide, in screens
+                #[uniffi(default = None)]
+                pub buffer_screens: Option<f64>,
+                /// Buffer used to size the live window, in screens (overrides `buffer_factor`)
+                #[uniffi(default = None)]
+                pub live_buffer_screens: Option<f64>,
+                /// Remaining buffer (in screens) at which pagination triggers
+                #[uniffi(default = None)]
+                pub trigger_screens: Option<f64>,
+                /// User scroll distance (in items) required between triggers
+                #[uniffi(default = None)]
+                pub debounce_items: Option<u32>,
+                /// Upper bound on window size in items
+                #[uniffi(default = None)]
+                pub max_window_size: Option<u32>,
+                /// Seconds of scrolling at the reported velocity to prefetch
+                #[uniffi(default = None)]
+                pub prefetch_seconds: Option<f64>,
+                /// Intersection failure recovery: "report", "retry_wider", "nearest_neighbor"
+                /// or "jump_to_live"
+                #[uniffi(default = None)]
+                pub intersection_recovery: Option<String>,
+                #[doc = #start_at_doc]
+                #[uniffi(default = None)]
+                pub start_at: Option<i64>,
+            }
+
+            /// Current scroll mode (mirrors `ScrollMode`)
+            #[derive(::uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+            pub enum #mode_name {
+                /// At the newest edge, auto-scrolling as items arrive
+                Live,
+                /// Scrolled back through earlier items
+                Backward,
+                /// Scrolling toward later items from mid-history
+                Forward,
+            }
+
+            impl From<::ankurah_virtual_scroll::ScrollMode> for #mode_name {
+                fn from(mode: ::ankurah_virtual_scroll::ScrollMode) -> Self {
+                    use ::ankurah_virtual_scroll::ScrollMode;
+                    match mode {
+                        ScrollMode::Live => Self::Live,
+                        ScrollMode::Backward => Self::Backward,
+                        ScrollMode::Forward => Self::Forward,
+                    }
+                }
+            }
+
+            /// Direction of a slide relative to display order (mirrors `LoadDirection`)
+            #[derive(::uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+            pub enum #direction_name {
+                /// Toward items preceding the window
+                Backward,
+                /// Toward items following the window
+                Forward,
+            }
+
+            impl From<::ankurah_virtual_scroll::LoadDirection> for #direction_name {
+                fn from(direction: ::ankurah_virtual_scroll::LoadDirection) -> Self {
+                    use ::ankurah_virtual_scroll::LoadDirection;
+                    match direction {
+                        LoadDirection::Backward => Self::Backward,
+                        LoadDirection::Forward => Self::Forward,
+                    }
+                }
+            }
+
+            /// Viewport edge where the newest items are shown
+            ///
+            /// Items are always delivered oldest first; with `Top`, render them reversed.
+            #[derive(::uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+            pub enum #live_edge_name {
+                /// Newest at the bottom (chat)
+                Bottom,
+                /// Newest at the top (feed)
+                Top,
+            }
+
+            /// Handle for a signal subscription: callbacks stop after `unsubscribe()` or once
+            /// the handle is dropped (e.g. from a React Native effect cleanup)
+            #[derive(::uniffi::Object)]
+            pub struct #subscription_name {
+                guard: ::std::sync::Mutex<Option<::ankurah_virtual_scroll::ankurah_signals::SubscriptionGuard>>,
+            }
+
+            #[::uniffi::export]
+            impl #subscription_name {
+                /// Stop delivering callbacks (idempotent)
+                #[uniffi::method]
+                pub fn unsubscribe(&self) {
+                    self.guard.lock().unwrap().take();
+                }
+
+                /// Whether callbacks are still delivered
+                #[uniffi::method]
+                pub fn is_active(&self) -> bool {
+                    self.guard.lock().unwrap().is_some()
+                }
+            }
+
+            impl #subscription_name {
+                fn new(guard: ::ankurah_virtual_scroll::ankurah_signals::SubscriptionGuard) -> Arc<Self> {
+                    Arc::new(Self { guard: ::std::sync::Mutex::new(Some(guard)) })
+                }
+            }
+
             /// Intersection item for scroll stability
             #[derive(::uniffi::Object)]
             pub struct #intersection_name {
                 entity_id: String,
                 index: u32,
-                direction: String,
+                direction: #direction_name,
             }
 
             #[::uniffi::export]
@@ -91,8 +341,92 @@ fn generate_impl(
                 }
 
                 #[uniffi::method]
-                pub fn direction(&self) -> String {
-                    self.direction.clone()
+                pub fn direction(&self) -> #direction_name {
+                    self.direction
+                }
+            }
+
+            /// Error returned by the manager's fallible methods (mirrors `ScrollError`)
+            #[derive(::uniffi::Error, Debug, Clone)]
+            pub enum #error_name {
+                #error_variants
+            }
+
+            impl ::std::fmt::Display for #error_name {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    match self {
+                        Self::IntersectionFailed { anchor, direction } => {
+                            write!(f, "Intersection failed: anchor {} not found in {:?} result", anchor, direction)
+                        }
+                        Self::QueryFailed { message } => write!(f, "Query failed: {}", message),
+                        Self::CursorConversionFailed { message } => write!(f, "Cursor conversion failed: {}", message),
+                        Self::InvalidState { message } => write!(f, "Invalid state: {}", message),
+                        Self::Timeout { millis } => write!(f, "Timed out after {}ms waiting for the initial result", millis),
+                        Self::Closed => write!(f, "Scroll manager closed"),
+                    }
+                }
+            }
+
+            impl ::std::error::Error for #error_name {}
+
+            impl From<&::ankurah_virtual_scroll::ScrollError> for #error_name {
+                fn from(error: &::ankurah_virtual_scroll::ScrollError) -> Self {
+                    #error_from
+                }
+            }
+
+            /// Failure reported on the visible set: the thrown error's variants as a plain enum,
+            /// which UniFFI can return as a value
+            #[derive(::uniffi::Enum, Debug, Clone)]
+            pub enum #error_info_name {
+                #error_variants
+            }
+
+            impl From<&::ankurah_virtual_scroll::ScrollError> for #error_info_name {
+                fn from(error: &::ankurah_virtual_scroll::ScrollError) -> Self {
+                    #error_from
+                }
+            }
+
+            /// Recovery applied after an intersection failure (mirrors `RecoveryAction`)
+            #[derive(::uniffi::Enum, Debug, Clone, PartialEq, Eq)]
+            pub enum #recovery_name {
+                /// Not recovered: the result was emitted without an intersection (`error` is set)
+                None,
+                /// The slide was re-issued with a wider window and found the anchor
+                RetriedWider,
+                /// The window was anchored on this item, the nearest remaining one by sort key
+                NearestNeighbor { entity_id: String },
+                /// The live window was reloaded
+                JumpedToLive,
+            }
+
+            /// A slide whose anchor item was missing from its result (mirrors `IntersectionFailure`)
+            #[derive(::uniffi::Record, Debug, Clone)]
+            pub struct #failure_name {
+                /// EntityId string of the anchor that was not found
+                pub anchor: String,
+                /// Direction of the failed slide
+                pub direction: #direction_name,
+                /// How the window was recovered
+                pub recovery: #recovery_name,
+            }
+
+            impl From<&::ankurah_virtual_scroll::IntersectionFailure> for #failure_name {
+                fn from(failure: &::ankurah_virtual_scroll::IntersectionFailure) -> Self {
+                    use ::ankurah_virtual_scroll::RecoveryAction;
+                    Self {
+                        anchor: failure.anchor.to_string(),
+                        direction: failure.direction.into(),
+                        recovery: match &failure.recovery {
+                            RecoveryAction::None => #recovery_name::None,
+                            RecoveryAction::RetriedWider => #recovery_name::RetriedWider,
+                            RecoveryAction::NearestNeighbor(id) => {
+                                #recovery_name::NearestNeighbor { entity_id: id.to_string() }
+                            }
+                            RecoveryAction::JumpedToLive => #recovery_name::JumpedToLive,
+                        },
+                    }
                 }
             }
 
@@ -104,6 +438,13 @@ fn generate_impl(
                 has_more_preceding: bool,
                 has_more_following: bool,
                 should_auto_scroll: bool,
+                error: Option<#error_info_name>,
+                intersection_failure: Option<#failure_name>,
+                updated_ids: Vec<String>,
+                moved_ids: Vec<String>,
+                loading_preceding: bool,
+                loading_following: bool,
+                initial_loading: bool,
             }
 
             #[::uniffi::export]
@@ -132,6 +473,48 @@ fn generate_impl(
                 pub fn should_auto_scroll(&self) -> bool {
                     self.should_auto_scroll
                 }
+
+                /// A slide toward earlier items is in flight
+                #[uniffi::method]
+                pub fn loading_preceding(&self) -> bool {
+                    self.loading_preceding
+                }
+
+                /// A slide toward later items (or a reload of the live window) is in flight
+                #[uniffi::method]
+                pub fn loading_following(&self) -> bool {
+                    self.loading_following
+                }
+
+                /// The initial window hasn't loaded yet
+                #[uniffi::method]
+                pub fn initial_loading(&self) -> bool {
+                    self.initial_loading
+                }
+
+                /// Failure behind this emission, if any
+                #[uniffi::method]
+                pub fn error(&self) -> Option<#error_info_name> {
+                    self.error.clone()
+                }
+
+                /// Intersection failure behind this emission and the recovery that was applied
+                #[uniffi::method]
+                pub fn intersection_failure(&self) -> Option<#failure_name> {
+                    self.intersection_failure.clone()
+                }
+
+                /// EntityId strings of items whose content changed in place (re-render only these rows)
+                #[uniffi::method]
+                pub fn updated_ids(&self) -> Vec<String> {
+                    self.updated_ids.clone()
+                }
+
+                /// EntityId strings of items whose sort position changed (moved within the window or out of it)
+                #[uniffi::method]
+                pub fn moved_ids(&self) -> Vec<String> {
+                    self.moved_ids.clone()
+                }
             }
 
             impl #visible_set_name {
@@ -140,7 +523,7 @@ fn generate_impl(
                         ::std::sync::Arc::new(#intersection_name {
                             entity_id: i.entity_id.to_string(),
                             index: i.index as u32,
-                            direction: format!("{:?}", i.direction),
+                            direction: i.direction.into(),
                         })
                     });
 
@@ -150,6 +533,13 @@ fn generate_impl(
                         has_more_preceding: core.has_more_preceding,
                         has_more_following: core.has_more_following,
                         should_auto_scroll: core.should_auto_scroll,
+                        error: core.error.as_ref().map(#error_info_name::from),
+                        intersection_failure: core.intersection_failure.as_ref().map(#failure_name::from),
+                        updated_ids: core.updated.iter().map(|id| id.to_string()).collect(),
+                        moved_ids: core.moved.iter().map(|id| id.to_string()).collect(),
+                        loading_preceding: core.loading_preceding,
+                        loading_following: core.loading_following,
+                        initial_loading: core.initial_loading,
                     })
                 }
             }
@@ -158,7 +548,6 @@ fn generate_impl(
             #[derive(::uniffi::Object)]
             pub struct #visible_set_signal_name {
                 manager: Arc<#scroll_manager_name>,
-                _subscriptions: ::std::sync::Mutex<Vec<::ankurah_virtual_scroll::ankurah_signals::SubscriptionGuard>>,
             }
 
             #[::uniffi::export]
@@ -168,8 +557,10 @@ fn generate_impl(
                     #visible_set_name::from_core(&self.manager.0.visible_set().get())
                 }
 
+                /// Call `callback` with the current value and every later one, until the
+                /// returned handle is unsubscribed or dropped
                 #[uniffi::method]
-                pub fn subscribe(&self, callback: Box<dyn #callback_name>) {
+                pub fn subscribe(&self, callback: Box<dyn #callback_name>) -> Arc<#subscription_name> {
                     let cb = Arc::new(callback);
                     let signal = self.manager.0.visible_set();
                     let initial = #visible_set_name::from_core(&signal.get());
@@ -177,8 +568,8 @@ fn generate_impl(
                     let guard = signal.subscribe(move |visible_set| {
                         cb_clone.on_change(#visible_set_name::from_core(&visible_set));
                     });
-                    self._subscriptions.lock().unwrap().push(guard);
                     cb.on_change(initial);
+                    #subscription_name::new(guard)
                 }
             }
 
@@ -186,7 +577,87 @@ fn generate_impl(
                 fn new(manager: Arc<#scroll_manager_name>) -> Arc<Self> {
                     Arc::new(Self {
                         manager,
-                        _subscriptions: ::std::sync::Mutex::new(Vec::new()),
+                    })
+                }
+            }
+
+            /// A single patch operation against the previously emitted window
+            #[derive(::uniffi::Enum, Clone)]
+            pub enum #change_name {
+                Remove { index: u32, ids: Vec<String> },
+                Move { from: u32, to: u32, id: String },
+                Insert { index: u32, items: Vec<::std::sync::Arc<#view_path>> },
+                Update { index: u32, item: ::std::sync::Arc<#view_path> },
+            }
+
+            /// Ordered changes that turn the previous visible set into the current one
+            #[derive(::uniffi::Object)]
+            pub struct #diff_name {
+                changes: Vec<#change_name>,
+            }
+
+            #[::uniffi::export]
+            impl #diff_name {
+                #[uniffi::method]
+                pub fn changes(&self) -> Vec<#change_name> {
+                    self.changes.clone()
+                }
+            }
+
+            impl #diff_name {
+                fn from_core(core: &::ankurah_virtual_scroll::VisibleSetDiff<#view_path>) -> Arc<Self> {
+                    use ::ankurah_virtual_scroll::VisibleSetChange;
+                    let changes = core.changes.iter().map(|change| match change {
+                        VisibleSetChange::Remove { index, ids } => #change_name::Remove {
+                            index: *index as u32,
+                            ids: ids.iter().map(|id| id.to_string()).collect(),
+                        },
+                        VisibleSetChange::Move { from, to, id } => #change_name::Move {
+                            from: *from as u32,
+                            to: *to as u32,
+                            id: id.to_string(),
+                        },
+                        VisibleSetChange::Insert { index, items } => #change_name::Insert {
+                            index: *index as u32,
+                            items: items.iter().map(|v| Arc::new(v.clone())).collect(),
+                        },
+                        VisibleSetChange::Update { index, item } => #change_name::Update {
+                            index: *index as u32,
+                            item: Arc::new(item.clone()),
+                        },
+                    }).collect();
+                    Arc::new(Self { changes })
+                }
+            }
+
+            /// Signal wrapper for visible_set_diff - exposes get() and subscribe()
+            #[derive(::uniffi::Object)]
+            pub struct #diff_signal_name {
+                manager: Arc<#scroll_manager_name>,
+            }
+
+            #[::uniffi::export]
+            impl #diff_signal_name {
+                #[uniffi::method]
+                pub fn get(&self) -> Arc<#diff_name> {
+                    #diff_name::from_core(&self.manager.0.visible_set_diff().get())
+                }
+
+                /// Subscribe to subsequent diffs (the current diff is not replayed), until the
+                /// returned handle is unsubscribed or dropped
+                #[uniffi::method]
+                pub fn subscribe(&self, callback: Box<dyn #diff_callback_name>) -> Arc<#subscription_name> {
+                    let guard = self.manager.0.visible_set_diff().subscribe(move |diff| {
+                        callback.on_change(#diff_name::from_core(&diff));
+                    });
+                    #subscription_name::new(guard)
+                }
+            }
+
+            impl #diff_signal_name {
+                fn new(manager: Arc<#scroll_manager_name>) -> Arc<Self> {
+                    Arc::new(Self {
+                        manager,
                     })
                 }
             }
@@ -201,6 +672,8 @@ fn generate_impl(
                 last_visible_index: u32,
                 update_count: u32,
                 update_pending: bool,
+                prefetch_distance: u32,
+                evicted_items: u32,
             }
 
             #[::uniffi::export]
@@ -239,6 +712,18 @@ fn generate_impl(
                 pub fn update_pending(&self) -> bool {
                     self.update_pending
                 }
+
+                /// Extra items prefetched for the reported scroll velocity
+                #[uniffi::method]
+                pub fn prefetch_distance(&self) -> u32 {
+                    self.prefetch_distance
+                }
+
+                /// Items evicted from the far side by the window cap on the last slide
+                #[uniffi::method]
+                pub fn evicted_items(&self) -> u32 {
+                    self.evicted_items
+                }
             }
 
             impl #debug_info_name {
@@ -251,6 +736,8 @@ fn generate_impl(
                         last_visible_index: core.last_visible_index as u32,
                         update_count: core.update_count,
                         update_pending: core.update_pending,
+                        prefetch_distance: core.prefetch_distance as u32,
+                        evicted_items: core.evicted_items as u32,
                     })
                 }
             }
@@ -259,7 +746,6 @@ fn generate_impl(
             #[derive(::uniffi::Object)]
             pub struct #debug_info_signal_name {
                 manager: Arc<#scroll_manager_name>,
-                _subscriptions: ::std::sync::Mutex<Vec<::ankurah_virtual_scroll::ankurah_signals::SubscriptionGuard>>,
             }
 
             #[::uniffi::export]
@@ -269,8 +755,10 @@ fn generate_impl(
                     #debug_info_name::from_core(&self.manager.0.debug_info().get())
                 }
 
+                /// Call `callback` with the current value and every later one, until the
+                /// returned handle is unsubscribed or dropped
                 #[uniffi::method]
-                pub fn subscribe(&self, callback: Box<dyn #debug_callback_name>) {
+                pub fn subscribe(&self, callback: Box<dyn #debug_callback_name>) -> Arc<#subscription_name> {
                     let cb = Arc::new(callback);
                     let signal = self.manager.0.debug_info();
                     let initial = #debug_info_name::from_core(&signal.get());
@@ -278,8 +766,8 @@ fn generate_impl(
                     let guard = signal.subscribe(move |debug_info| {
                         cb_clone.on_change(#debug_info_name::from_core(&debug_info));
                     });
-                    self._subscriptions.lock().unwrap().push(guard);
                     cb.on_change(initial);
+                    #subscription_name::new(guard)
                 }
             }
 
@@ -287,7 +775,6 @@ fn generate_impl(
                 fn new(manager: Arc<#scroll_manager_name>) -> Arc<Self> {
                     Arc::new(Self {
                         manager,
-                        _subscriptions: ::std::sync::Mutex::new(Vec::new()),
                     })
                 }
             }
@@ -328,42 +815,71 @@ fn generate_impl(
                     )?)))
                 }
 
-                #[uniffi::method]
-                pub fn visible_set(self: Arc<Self>) -> Arc<#visible_set_signal_name> {
-                    #visible_set_signal_name::new(self)
+                /// Create a scroll manager from an options record
+                #[uniffi::constructor]
+                pub fn with_options(
+                    ctx: &::ankurah::Context,
+                    options: #options_name,
+                ) -> Result<Arc<Self>, ::ankurah::error::RetrievalError> {
+                    let mut config = #default_config;
+                    if let Some(buffer_factor) = options.buffer_factor {
+                        config = config.buffer_factor(buffer_factor);
+                    }
+                    if let Some(buffer_screens) = options.buffer_screens {
+                        config = config.buffer_screens(buffer_screens);
+                    }
+                    if let Some(live_buffer_screens) = options.live_buffer_screens {
+                        config = config.live_buffer_screens(live_buffer_screens);
+                    }
+                    if let Some(trigger_screens) = options.trigger_screens {
+                        config = config.trigger_screens(trigger_screens);
+                    }
+                    if let Some(debounce_items) = options.debounce_items {
+                        config = config.debounce_items(debounce_items as usize);
+                    }
+                    if let Some(max_window_size) = options.max_window_size {
+                        config = config.max_window_size(max_window_size as usize);
+                    }
+                    if let Some(prefetch_seconds) = options.prefetch_seconds {
+                        config = config.prefetch_seconds(prefetch_seconds);
+                    }
+                    if let Some(strategy) = options.intersection_recovery {
+                        let strategy = strategy.parse().map_err(::ankurah::error::RetrievalError::Other)?;
+                        config = config.intersection_recovery(strategy);
+                    }
+
+                    let predicate = options.predicate.unwrap_or_else(|| #default_predicate.to_string());
+                    let mut builder = ::ankurah_virtual_scroll::ScrollManager::<#view_path>::builder(ctx)
+                        .predicate(predicate.as_str())
+                        .order_by(options.order_by.as_deref().unwrap_or(#default_order_by))
+                        .viewport(options.viewport_height)
+                        .row_height(options.minimum_row_height)
+                        .config(config);
+                    if let Some(start_at) = options.start_at {
+                        builder = builder.start_at(start_at);
+                    }
+                    Ok(Arc::new(Self(builder.build()?)))
                 }
 
-                /// Initialize the scroll manager and populate initial items
-                #[uniffi::method]
-                pub async fn start(self: Arc<Self>) {
-                    self.0.start().await;
-                }
+                #convenience_constructors
 
-                /// Process a scroll event
-                ///
-                /// # Arguments
-                /// * `first_visible` - EntityId string of the first (oldest) visible item
-                /// * `last_visible` - EntityId string of the last (newest) visible item
-                /// * `scrolling_backward` - True if user is scrolling toward older items
                 #[uniffi::method]
-                pub fn on_scroll(self: Arc<Self>, first_visible: String, last_visible: String, scrolling_backward: bool) {
-                    let first_id: ::ankurah_virtual_scroll::Id = first_visible.parse()
-                        .expect("Invalid first_visible EntityId");
-                    let last_id: ::ankurah_virtual_scroll::Id = last_visible.parse()
-                        .expect("Invalid last_visible EntityId");
-                    self.0.on_scroll(first_id, last_id, scrolling_backward);
+                pub fn visible_set(self: Arc<Self>) -> Arc<#visible_set_signal_name> {
+                    #visible_set_signal_name::new(self)
                 }
 
-                /// Get the current scroll mode
+                /// Get the incremental diff signal (for renderers that apply patches)
                 #[uniffi::method]
-                pub fn mode(&self) -> String {
-                    format!("{:?}", self.0.mode())
+                pub fn visible_set_diff(self: Arc<Self>) -> Arc<#diff_signal_name> {
+                    #diff_signal_name::new(self)
                 }
 
-                /// Get the current selection (predicate + order by) as a string
+                #(#forwarded)*
+
+                /// Viewport edge where the newest items are shown
                 #[uniffi::method]
-                pub fn current_selection(&self) -> String {
-                    self.0.current_selection()
+                pub fn live_edge(&self) -> #live_edge_name {
+                    #live_edge
                 }
 
                 /// Get debug info signal for scroll position and buffer state
@@ -375,6 +891,42 @@ fn generate_impl(
         }
 
         #[cfg(feature = "uniffi")]
-        pub use __uniffi_scroll_manager::*;
+        pub use #module_name::*;
+    }
+}
+
+/// `#[uniffi::method]` forwarding to the wrapped `ScrollManager`
+fn forward_method(method: &Method, mode_name: &Ident, error_name: &Ident) -> TokenStream {
+    let name = method.ident();
+    let docs = method.doc_attrs();
+    // Async methods outlive the call, so they hold the object
+    let receiver = if method.is_async { quote!(self: Arc<Self>) } else { quote!(&self) };
+    let params = method.params.iter().map(|param| {
+        let ident = param.ident();
+        let ty = match param.ty {
+            ParamType::EntityId | ParamType::Predicate => quote!(String),
+            ParamType::Bool => quote!(bool),
+            ParamType::U32 => quote!(u32),
+            ParamType::F64 => quote!(f64),
+            ParamType::Millis => quote!(u64),
+        };
+        quote!(#ident: #ty)
+    });
+    let call = method.core_call(quote!(self.0), methods::convert_param);
+    let (output, body) = match method.returns {
+        Returns::Unit => (quote!(), quote!(#call;)),
+        Returns::Bool => (quote!(-> bool), call),
+        Returns::String => (quote!(-> String), call),
+        Returns::Mode => (quote!(-> #mode_name), quote!(#call.into())),
+        Returns::Result => (quote!(-> Result<(), #error_name>), quote!(#call.map_err(|e| #error_name::from(&e)))),
+    };
+    let async_ = if method.is_async { quote!(async) } else { quote!() };
+
+    quote! {
+        #docs
+        #[uniffi::method]
+        pub #async_ fn #name(#receiver, #(#params),*) #output {
+            #body
+        }
     }
 }
@@ -6,17 +6,20 @@
 //! with wasm_bindgen-compatible methods.
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{Ident, Path};
 
+use crate::methods::{self, Method, ParamType, Returns};
+use crate::{Defaults, LiveEdge};
+
 /// Generate WASM implementation for the scroll manager (with paths)
 pub fn generate_with_paths(
     scroll_manager_name: &Ident,
     view_path: &Path,
     _livequery_path: &Path,
-    timestamp_field: &str,
+    defaults: &Defaults,
 ) -> TokenStream {
-    generate_impl(scroll_manager_name, quote!(#view_path), timestamp_field)
+    generate_impl(scroll_manager_name, quote!(#view_path), defaults)
 }
 
 /// Generate WASM implementation for the scroll manager (with idents - for backwards compat)
@@ -24,18 +27,21 @@ pub fn generate(
     scroll_manager_name: &Ident,
     view_name: &Ident,
     _livequery_name: &Ident,
-    timestamp_field: &str,
+    defaults: &Defaults,
 ) -> TokenStream {
-    generate_impl(scroll_manager_name, quote!(#view_name), timestamp_field)
+    generate_impl(scroll_manager_name, quote!(#view_name), defaults)
 }
 
 fn generate_impl(
     scroll_manager_name: &Ident,
     view_type: TokenStream,
-    _timestamp_field: &str,
+    defaults: &Defaults,
 ) -> TokenStream {
     // Extract the model name from scroll manager name (e.g., "Message" from "MessageScrollManager")
-    let model_name = scroll_manager_name.to_string().replace("ScrollManager", "");
+    let model_name = match crate::model_prefix(scroll_manager_name) {
+        Ok(model_name) => model_name,
+        Err(e) => return e.to_compile_error(),
+    };
 
     // Generate names following the pattern: {Model}VisibleSet and {Model}VisibleSetSignal
     let visible_set_signal_name = syn::Ident::new(
@@ -46,25 +52,305 @@ fn generate_impl(
         &format!("{}VisibleSet", model_name),
         scroll_manager_name.span(),
     );
+    let diff_name = syn::Ident::new(
+        &format!("{}VisibleSetDiff", model_name),
+        scroll_manager_name.span(),
+    );
+    let diff_signal_name = syn::Ident::new(
+        &format!("{}VisibleSetDiffSignal", model_name),
+        scroll_manager_name.span(),
+    );
+
+    let debug_info_name = syn::Ident::new(
+        &format!("{}ScrollDebugInfo", model_name),
+        scroll_manager_name.span(),
+    );
+    let debug_info_signal_name = syn::Ident::new(
+        &format!("{}ScrollDebugInfoSignal", model_name),
+        scroll_manager_name.span(),
+    );
+
+    let mode_name = syn::Ident::new(
+        &format!("{}ScrollMode", model_name),
+        scroll_manager_name.span(),
+    );
+    let direction_name = syn::Ident::new(
+        &format!("{}LoadDirection", model_name),
+        scroll_manager_name.span(),
+    );
+
+    let options_name = syn::Ident::new(
+        &format!("{}ScrollOptions", model_name),
+        scroll_manager_name.span(),
+    );
+    let options_ts_type = options_name.to_string();
+    let error_ts_type = format!("{}ScrollError | undefined", model_name);
+    let intersection_ts_type = format!("{}Intersection | null", model_name);
+    let failure_ts_type = format!("{}IntersectionFailure | undefined", model_name);
+    let live_edge_name = syn::Ident::new(
+        &format!("{}LiveEdge", model_name),
+        scroll_manager_name.span(),
+    );
+    let subscription_name = format_ident!("{}ScrollSubscription", model_name);
+    let visible_set_callback_ts = format!("(visibleSet: {}) => void", visible_set_name);
+    let diff_callback_ts = format!("(diff: {}) => void", diff_name);
+    let debug_info_callback_ts = format!("(debugInfo: {}) => void", debug_info_name);
+    let module_name = format_ident!("__wasm_{}", crate::to_snake_case(&scroll_manager_name.to_string()));
+
+    let default_order_by = &defaults.order_by;
+    let default_predicate = &defaults.predicate;
+    let live_edge = match defaults.live_edge {
+        LiveEdge::Bottom => quote!(#live_edge_name::Bottom),
+        LiveEdge::Top => quote!(#live_edge_name::Top),
+    };
+    let default_config = match defaults.buffer_factor {
+        Some(buffer_factor) => quote!(::ankurah_virtual_scroll::ScrollConfig::default().buffer_factor(#buffer_factor)),
+        None => quote!(::ankurah_virtual_scroll::ScrollConfig::default()),
+    };
+    let (row_height_ts, minimum_row_height) = match defaults.min_row_height {
+        Some(height) => (
+            format!("/** Guaranteed minimum item height in pixels (default {}) */\n    minimumRowHeight?: number;", height),
+            quote!(number("minimumRowHeight").map_or(#height, |height| height as u32)),
+        ),
+        None => (
+            "/** Guaranteed minimum item height in pixels */\n    minimumRowHeight: number;".to_string(),
+            quote!(required("minimumRowHeight")? as u32),
+        ),
+    };
+    // Constructors that need only the viewport, when the macro supplied a row height
+    let convenience_constructors = match defaults.min_row_height {
+        Some(height) => quote! {
+            /// Create a scroll manager with the default predicate, order and row height
+            #[wasm_bindgen(js_name = withViewport)]
+            pub fn with_viewport(
+                ctx: &::ankurah::core::context::Context,
+                viewport_height: u32,
+            ) -> Result<#scroll_manager_name, JsValue> {
+                Self::with_predicate(ctx, #default_predicate.to_string(), viewport_height)
+            }
+
+            /// Create a scroll manager with the default order and row height
+            #[wasm_bindgen(js_name = withPredicate)]
+            pub fn with_predicate(
+                ctx: &::ankurah::core::context::Context,
+                predicate: String,
+                viewport_height: u32,
+            ) -> Result<#scroll_manager_name, JsValue> {
+                let manager = ::ankurah_virtual_scroll::ScrollManager::<#view_type>::builder(ctx)
+                    .predicate(predicate.as_str())
+                    .order_by(#default_order_by)
+                    .viewport(viewport_height)
+                    .row_height(#height)
+                    .config(#default_config)
+                    .build()
+                    .map_err(|e| JsValue::from_str(&format!("Failed to create ScrollManager: {:?}", e)))?;
+
+                Ok(Self {
+                    inner: Rc::new(manager),
+                })
+            }
+        },
+        None => quote!(),
+    };
+    let forwarded = methods::METHODS.iter().map(|method| forward_method(method, &mode_name));
+    let default_buffer_factor = defaults.buffer_factor.unwrap_or(2.0);
+    let options_ts = format!(
+        r#"
+/** Construction options for {model}ScrollManager.withOptions (mirrors ScrollManager::builder) */
+export interface {model}ScrollOptions {{
+    /** ORDER BY clause (default "{default_order_by}") */
+    orderBy?: string;
+    /** Viewport height in pixels */
+    viewportHeight: number;
+    {row_height_ts}
+    /** Base filter predicate (default "{default_predicate}") */
+    predicate?: string;
+    /** Buffer as multiple of viewport (default {default_buffer_factor:?}) */
+    bufferFactor?: number;
+    /** Items kept beyond the visible area on each side after a slide, in screens (default 2.0) */
+    bufferScreens?: number;
+    /** Buffer used to size the live window, in screens (overrides bufferFactor) */
+    liveBufferScreens?: number;
+    /** Remaining buffer (in screens) at which pagination triggers (default 1.0) */
+    triggerScreens?: number;
+    /** User scroll distance (in items) required between triggers (default one screen) */
+    debounceItems?: number;
+    /** Upper bound on window size in items */
+    maxWindowSize?: number;
+    /** Seconds of scrolling at the reported velocity to prefetch (default 0.5) */
+    prefetchSeconds?: number;
+    /** Intersection failure recovery (default "nearest_neighbor") */
+    intersectionRecovery?: "report" | "retry_wider" | "nearest_neighbor" | "jump_to_live";
+    /** Open the window at this `{timestamp_field}` value instead of the live edge */
+    startAt?: number;
+}}
+
+/** Failure reported on {model}VisibleSet.error (mirrors the Rust ScrollError) */
+export type {model}ScrollError =
+    | {{ kind: "intersectionFailed"; message: string; anchor: string; direction: {model}LoadDirection }}
+    | {{ kind: "queryFailed"; message: string }}
+    | {{ kind: "cursorConversionFailed"; message: string }}
+    | {{ kind: "invalidState"; message: string }}
+    | {{ kind: "timeout"; message: string }}
+    | {{ kind: "closed"; message: string }};
+
+/** Intersection failure on {model}VisibleSet.intersectionFailure and the recovery applied */
+export interface {model}IntersectionFailure {{
+    /** EntityId of the anchor that was not found */
+    anchor: string;
+    /** Direction of the failed slide */
+    direction: {model}LoadDirection;
+    /** How the window was recovered (see ScrollOptions.intersectionRecovery) */
+    recovery: "none" | "retriedWider" | "nearestNeighbor" | "jumpedToLive";
+    /** EntityId the window was anchored on instead (nearestNeighbor only) */
+    neighbor?: string;
+}}
+
+/** Anchor item for scroll stability, on {model}VisibleSet.intersection */
+export interface {model}Intersection {{
+    /** EntityId of the anchor item */
+    entityId: string;
+    /** Index of the anchor in the new items */
+    index: number;
+    /** Direction of the slide that produced this window */
+    direction: {model}LoadDirection;
+}}
+"#,
+        model = model_name,
+        default_order_by = default_order_by,
+        default_predicate = default_predicate,
+        default_buffer_factor = default_buffer_factor,
+        row_height_ts = row_height_ts,
+        timestamp_field = defaults.timestamp_field
+    );
 
     quote! {
         #[cfg(feature = "wasm")]
-        mod __wasm_scroll_manager {
+        mod #module_name {
             use super::*;
             use ::wasm_bindgen::prelude::*;
             use ::ankurah_signals::Peek;
             use ::std::cell::RefCell;
             use ::std::rc::Rc;
 
+            #[wasm_bindgen(typescript_custom_section)]
+            const SCROLL_OPTIONS_TS: &'static str = #options_ts;
+
+            #[wasm_bindgen]
+            extern "C" {
+                /// Plain JS options object (see the generated TypeScript interface)
+                #[wasm_bindgen(typescript_type = #options_ts_type)]
+                pub type #options_name;
+            }
+
+            /// Current scroll mode (mirrors `ScrollMode`)
+            #[wasm_bindgen]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub enum #mode_name {
+                Live = "Live",
+                Backward = "Backward",
+                Forward = "Forward",
+            }
+
+            impl From<::ankurah_virtual_scroll::ScrollMode> for #mode_name {
+                fn from(mode: ::ankurah_virtual_scroll::ScrollMode) -> Self {
+                    use ::ankurah_virtual_scroll::ScrollMode;
+                    match mode {
+                        ScrollMode::Live => Self::Live,
+                        ScrollMode::Backward => Self::Backward,
+                        ScrollMode::Forward => Self::Forward,
+                    }
+                }
+            }
+
+            /// Direction of a slide relative to display order (mirrors `LoadDirection`)
+            #[wasm_bindgen]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub enum #direction_name {
+                Backward = "Backward",
+                Forward = "Forward",
+            }
+
+            impl From<::ankurah_virtual_scroll::LoadDirection> for #direction_name {
+                fn from(direction: ::ankurah_virtual_scroll::LoadDirection) -> Self {
+                    use ::ankurah_virtual_scroll::LoadDirection;
+                    match direction {
+                        LoadDirection::Backward => Self::Backward,
+                        LoadDirection::Forward => Self::Forward,
+                    }
+                }
+            }
+
+            /// Viewport edge where the newest items are shown
+            ///
+            /// Items are always delivered oldest first; with `Top`, render them reversed.
+            #[wasm_bindgen]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub enum #live_edge_name {
+                Bottom = "Bottom",
+                Top = "Top",
+            }
+
+            /// `{ kind, message, ... }` object matching the generated ScrollError type
+            fn scroll_error_to_js(error: &::ankurah_virtual_scroll::ScrollError) -> JsValue {
+                use ::ankurah::derive_deps::js_sys::{Object, Reflect};
+                use ::ankurah_virtual_scroll::ScrollError;
+
+                let obj = Object::new();
+                let set = |key: &str, value: JsValue| {
+                    let _ = Reflect::set(&obj, &JsValue::from_str(key), &value);
+                };
+                set("kind", JsValue::from_str(error.kind()));
+                set("message", JsValue::from_str(&error.to_string()));
+                if let ScrollError::IntersectionFailed { anchor, direction } = error {
+                    set("anchor", JsValue::from_str(&anchor.to_string()));
+                    set("direction", #direction_name::from(*direction).into());
+                }
+                obj.into()
+            }
+
+            /// Handle for a signal subscription: callbacks stop after `unsubscribe()` or once
+            /// the handle is freed (e.g. from a React effect cleanup)
+            #[wasm_bindgen]
+            pub struct #subscription_name {
+                guard: RefCell<Option<::ankurah_signals::SubscriptionGuard>>,
+            }
+
+            #[wasm_bindgen]
+            impl #subscription_name {
+                /// Stop delivering callbacks (idempotent)
+                pub fn unsubscribe(&self) {
+                    self.guard.borrow_mut().take();
+                }
+
+                /// Whether callbacks are still delivered
+                #[wasm_bindgen(getter, js_name = isActive)]
+                pub fn is_active(&self) -> bool {
+                    self.guard.borrow().is_some()
+                }
+            }
+
+            impl #subscription_name {
+                fn new(guard: ::ankurah_signals::SubscriptionGuard) -> Self {
+                    Self { guard: RefCell::new(Some(guard)) }
+                }
+            }
+
             /// WASM wrapper for VisibleSet data
             #[wasm_bindgen]
             pub struct #visible_set_name {
                 items: Vec<#view_type>,
-                intersection_entity_id: Option<String>,
-                intersection_index: Option<usize>,
+                intersection: Option<::ankurah_virtual_scroll::Intersection>,
                 has_more_preceding: bool,
                 has_more_following: bool,
                 should_auto_scroll: bool,
+                error: Option<::ankurah_virtual_scroll::ScrollError>,
+                intersection_failure: Option<::ankurah_virtual_scroll::IntersectionFailure>,
+                updated_ids: Vec<String>,
+                moved_ids: Vec<String>,
+                loading_preceding: bool,
+                loading_following: bool,
+                initial_loading: bool,
             }
 
             #[wasm_bindgen]
@@ -74,41 +360,106 @@ fn generate_impl(
                     self.items.clone()
                 }
 
-                #[wasm_bindgen(js_name = hasMorePreceding)]
+                #[wasm_bindgen(getter, js_name = hasMorePreceding)]
                 pub fn has_more_preceding(&self) -> bool {
                     self.has_more_preceding
                 }
 
-                #[wasm_bindgen(js_name = hasMoreFollowing)]
+                #[wasm_bindgen(getter, js_name = hasMoreFollowing)]
                 pub fn has_more_following(&self) -> bool {
                     self.has_more_following
                 }
 
-                #[wasm_bindgen(js_name = shouldAutoScroll)]
+                #[wasm_bindgen(getter, js_name = shouldAutoScroll)]
                 pub fn should_auto_scroll(&self) -> bool {
                     self.should_auto_scroll
                 }
 
-                /// Get the intersection item info (for scroll stability)
-                pub fn intersection(&self) -> JsValue {
-                    match (&self.intersection_entity_id, &self.intersection_index) {
-                        (Some(entity_id), Some(index)) => {
-                            let obj = ::ankurah::derive_deps::js_sys::Object::new();
-                            let _ = ::ankurah::derive_deps::js_sys::Reflect::set(
-                                &obj,
-                                &JsValue::from_str("entityId"),
-                                &JsValue::from_str(entity_id),
-                            );
-                            let _ = ::ankurah::derive_deps::js_sys::Reflect::set(
-                                &obj,
-                                &JsValue::from_str("index"),
-                                &JsValue::from_f64(*index as f64),
-                            );
-                            obj.into()
-                        }
-                        _ => JsValue::NULL,
+                /// A slide toward earlier items is in flight (spinner at the leading edge)
+                #[wasm_bindgen(getter, js_name = loadingPreceding)]
+                pub fn loading_preceding(&self) -> bool {
+                    self.loading_preceding
+                }
+
+                /// A slide toward later items (or a reload of the live window) is in flight
+                #[wasm_bindgen(getter, js_name = loadingFollowing)]
+                pub fn loading_following(&self) -> bool {
+                    self.loading_following
+                }
+
+                /// The initial window hasn't loaded yet
+                #[wasm_bindgen(getter, js_name = initialLoading)]
+                pub fn initial_loading(&self) -> bool {
+                    self.initial_loading
+                }
+
+                /// EntityIds of items whose content changed in place (re-render only these rows)
+                #[wasm_bindgen(getter, js_name = updatedIds)]
+                pub fn updated_ids(&self) -> Vec<String> {
+                    self.updated_ids.clone()
+                }
+
+                /// EntityIds of items whose sort position changed (moved within the window or out of it)
+                #[wasm_bindgen(getter, js_name = movedIds)]
+                pub fn moved_ids(&self) -> Vec<String> {
+                    self.moved_ids.clone()
+                }
+
+                /// Failure behind this emission: `{ kind, message, ... }`, or undefined
+                #[wasm_bindgen(getter, unchecked_return_type = #error_ts_type)]
+                pub fn error(&self) -> JsValue {
+                    match &self.error {
+                        Some(error) => scroll_error_to_js(error),
+                        None => JsValue::UNDEFINED,
                     }
                 }
+
+                /// Intersection failure behind this emission and the recovery that was applied:
+                /// `{ anchor, direction, recovery, neighbor? }`, or undefined
+                #[wasm_bindgen(getter, js_name = intersectionFailure, unchecked_return_type = #failure_ts_type)]
+                pub fn intersection_failure(&self) -> JsValue {
+                    use ::ankurah::derive_deps::js_sys::{Object, Reflect};
+                    use ::ankurah_virtual_scroll::RecoveryAction;
+
+                    let Some(failure) = &self.intersection_failure else {
+                        return JsValue::UNDEFINED;
+                    };
+                    let obj = Object::new();
+                    let set = |key: &str, value: JsValue| {
+                        let _ = Reflect::set(&obj, &JsValue::from_str(key), &value);
+                    };
+                    set("anchor", JsValue::from_str(&failure.anchor.to_string()));
+                    set("direction", #direction_name::from(failure.direction).into());
+                    let recovery = match &failure.recovery {
+                        RecoveryAction::None => "none",
+                        RecoveryAction::RetriedWider => "retriedWider",
+                        RecoveryAction::NearestNeighbor(neighbor) => {
+                            set("neighbor", JsValue::from_str(&neighbor.to_string()));
+                            "nearestNeighbor"
+                        }
+                        RecoveryAction::JumpedToLive => "jumpedToLive",
+                    };
+                    set("recovery", JsValue::from_str(recovery));
+                    obj.into()
+                }
+
+                /// Get the intersection item info (for scroll stability): `{ entityId, index, direction }`, or null
+                #[wasm_bindgen(getter, unchecked_return_type = #intersection_ts_type)]
+                pub fn intersection(&self) -> JsValue {
+                    use ::ankurah::derive_deps::js_sys::{Object, Reflect};
+
+                    let Some(intersection) = &self.intersection else {
+                        return JsValue::NULL;
+                    };
+                    let obj = Object::new();
+                    let set = |key: &str, value: JsValue| {
+                        let _ = Reflect::set(&obj, &JsValue::from_str(key), &value);
+                    };
+                    set("entityId", JsValue::from_str(&intersection.entity_id.to_string()));
+                    set("index", JsValue::from_f64(intersection.index as f64));
+                    set("direction", #direction_name::from(intersection.direction).into());
+                    obj.into()
+                }
             }
 
             /// WASM wrapper for VisibleSet signal - call .get() to read current value
@@ -125,18 +476,222 @@ fn generate_impl(
                 /// will automatically subscribe to changes.
                 pub fn get(&self) -> #visible_set_name {
                     use ::ankurah_signals::Get;
-                    let vs = self.inner.get();
-                    #visible_set_name {
+                    #visible_set_name::from_core(&self.inner.get())
+                }
+
+                /// Call `callback` with the current value and every later one, until the
+                /// returned handle is unsubscribed or freed
+                pub fn subscribe(
+                    &self,
+                    #[wasm_bindgen(unchecked_param_type = #visible_set_callback_ts)] callback: ::ankurah::derive_deps::js_sys::Function,
+                ) -> #subscription_name {
+                    use ::ankurah_signals::Subscribe;
+                    let callback = ::ankurah::derive_deps::send_wrapper::SendWrapper::new(callback);
+                    let _ = callback.call1(&JsValue::NULL, &#visible_set_name::from_core(&self.inner.peek()).into());
+                    let guard = self.inner.subscribe(move |vs: ::ankurah_virtual_scroll::VisibleSet<#view_type>| {
+                        let _ = callback.call1(&JsValue::NULL, &#visible_set_name::from_core(&vs).into());
+                    });
+                    #subscription_name::new(guard)
+                }
+            }
+
+            impl #visible_set_name {
+                fn from_core(vs: &::ankurah_virtual_scroll::VisibleSet<#view_type>) -> Self {
+                    Self {
                         items: vs.items.clone(),
-                        intersection_entity_id: vs.intersection.as_ref().map(|i| i.entity_id.to_string()),
-                        intersection_index: vs.intersection.as_ref().map(|i| i.index),
+                        intersection: vs.intersection.clone(),
                         has_more_preceding: vs.has_more_preceding,
                         has_more_following: vs.has_more_following,
                         should_auto_scroll: vs.should_auto_scroll,
+                        error: vs.error.clone(),
+                        intersection_failure: vs.intersection_failure.clone(),
+                        updated_ids: vs.updated.iter().map(|id| id.to_string()).collect(),
+                        moved_ids: vs.moved.iter().map(|id| id.to_string()).collect(),
+                        loading_preceding: vs.loading_preceding,
+                        loading_following: vs.loading_following,
+                        initial_loading: vs.initial_loading,
                     }
                 }
             }
 
+            /// WASM wrapper for VisibleSetDiff - ordered patch operations against the previous window
+            #[wasm_bindgen]
+            pub struct #diff_name {
+                inner: ::ankurah_virtual_scroll::VisibleSetDiff<#view_type>,
+            }
+
+            #[wasm_bindgen]
+            impl #diff_name {
+                /// Get the changes in application order
+                ///
+                /// Each change is a plain object with a `kind` of "remove" (`index`, `ids`),
+                /// "move" (`from`, `to`, `id`), "insert" (`index`, `items`) or "update" (`index`, `item`).
+                #[wasm_bindgen(getter)]
+                pub fn changes(&self) -> ::ankurah::derive_deps::js_sys::Array {
+                    use ::ankurah::derive_deps::js_sys::{Array, Object, Reflect};
+                    use ::ankurah_virtual_scroll::VisibleSetChange;
+
+                    let set = |obj: &Object, key: &str, value: JsValue| {
+                        let _ = Reflect::set(obj, &JsValue::from_str(key), &value);
+                    };
+                    let changes = Array::new();
+                    for change in &self.inner.changes {
+                        let obj = Object::new();
+                        match change {
+                            VisibleSetChange::Remove { index, ids } => {
+                                set(&obj, "kind", JsValue::from_str("remove"));
+                                set(&obj, "index", JsValue::from_f64(*index as f64));
+                                let js_ids: Array = ids.iter().map(|id| JsValue::from_str(&id.to_string())).collect();
+                                set(&obj, "ids", js_ids.into());
+                            }
+                            VisibleSetChange::Move { from, to, id } => {
+                                set(&obj, "kind", JsValue::from_str("move"));
+                                set(&obj, "from", JsValue::from_f64(*from as f64));
+                                set(&obj, "to", JsValue::from_f64(*to as f64));
+                                set(&obj, "id", JsValue::from_str(&id.to_string()));
+                            }
+                            VisibleSetChange::Insert { index, items } => {
+                                set(&obj, "kind", JsValue::from_str("insert"));
+                                set(&obj, "index", JsValue::from_f64(*index as f64));
+                                let js_items: Array = items.iter().cloned().map(JsValue::from).collect();
+                                set(&obj, "items", js_items.into());
+                            }
+                            VisibleSetChange::Update { index, item } => {
+                                set(&obj, "kind", JsValue::from_str("update"));
+                                set(&obj, "index", JsValue::from_f64(*index as f64));
+                                set(&obj, "item", JsValue::from(item.clone()));
+                            }
+                        }
+                        changes.push(&obj);
+                    }
+                    changes
+                }
+            }
+
+            /// WASM wrapper for VisibleSetDiff signal - call .get() to read the latest diff
+            #[wasm_bindgen]
+            pub struct #diff_signal_name {
+                inner: ::ankurah_signals::Read<::ankurah_virtual_scroll::VisibleSetDiff<#view_type>>,
+            }
+
+            #[wasm_bindgen]
+            impl #diff_signal_name {
+                /// Get the diff behind the most recent visible set change
+                pub fn get(&self) -> #diff_name {
+                    use ::ankurah_signals::Get;
+                    #diff_name {
+                        inner: self.inner.get(),
+                    }
+                }
+
+                /// Call `callback` with subsequent diffs (the current diff is not replayed),
+                /// until the returned handle is unsubscribed or freed
+                pub fn subscribe(
+                    &self,
+                    #[wasm_bindgen(unchecked_param_type = #diff_callback_ts)] callback: ::ankurah::derive_deps::js_sys::Function,
+                ) -> #subscription_name {
+                    use ::ankurah_signals::Subscribe;
+                    let callback = ::ankurah::derive_deps::send_wrapper::SendWrapper::new(callback);
+                    let guard = self.inner.subscribe(move |diff: ::ankurah_virtual_scroll::VisibleSetDiff<#view_type>| {
+                        let _ = callback.call1(&JsValue::NULL, &#diff_name { inner: diff }.into());
+                    });
+                    #subscription_name::new(guard)
+                }
+            }
+
+            /// Debug info about scroll position and buffer state
+            #[wasm_bindgen]
+            pub struct #debug_info_name {
+                inner: ::ankurah_virtual_scroll::ScrollDebugInfo,
+            }
+
+            #[wasm_bindgen]
+            impl #debug_info_name {
+                /// Items above the visible area (buffer before)
+                #[wasm_bindgen(getter, js_name = itemsAbove)]
+                pub fn items_above(&self) -> u32 {
+                    self.inner.items_above as u32
+                }
+
+                /// Items below the visible area (buffer after)
+                #[wasm_bindgen(getter, js_name = itemsBelow)]
+                pub fn items_below(&self) -> u32 {
+                    self.inner.items_below as u32
+                }
+
+                /// Remaining buffer (in items) at which pagination triggers
+                #[wasm_bindgen(getter, js_name = triggerThreshold)]
+                pub fn trigger_threshold(&self) -> u32 {
+                    self.inner.trigger_threshold as u32
+                }
+
+                #[wasm_bindgen(getter, js_name = firstVisibleIndex)]
+                pub fn first_visible_index(&self) -> u32 {
+                    self.inner.first_visible_index as u32
+                }
+
+                #[wasm_bindgen(getter, js_name = lastVisibleIndex)]
+                pub fn last_visible_index(&self) -> u32 {
+                    self.inner.last_visible_index as u32
+                }
+
+                /// Number of pagination updates initiated
+                #[wasm_bindgen(getter, js_name = updateCount)]
+                pub fn update_count(&self) -> u32 {
+                    self.inner.update_count
+                }
+
+                /// Whether a pagination update is currently pending
+                #[wasm_bindgen(getter, js_name = updatePending)]
+                pub fn update_pending(&self) -> bool {
+                    self.inner.update_pending
+                }
+
+                /// Extra items prefetched for the reported scroll velocity
+                #[wasm_bindgen(getter, js_name = prefetchDistance)]
+                pub fn prefetch_distance(&self) -> u32 {
+                    self.inner.prefetch_distance as u32
+                }
+
+                /// Items evicted from the far side by the window cap on the last slide
+                #[wasm_bindgen(getter, js_name = evictedItems)]
+                pub fn evicted_items(&self) -> u32 {
+                    self.inner.evicted_items as u32
+                }
+            }
+
+            /// WASM wrapper for ScrollDebugInfo signal - call .get() to read current value
+            #[wasm_bindgen]
+            pub struct #debug_info_signal_name {
+                inner: ::ankurah_signals::Read<::ankurah_virtual_scroll::ScrollDebugInfo>,
+            }
+
+            #[wasm_bindgen]
+            impl #debug_info_signal_name {
+                /// Get the current debug info (subscribes when read inside a signalObserver)
+                pub fn get(&self) -> #debug_info_name {
+                    use ::ankurah_signals::Get;
+                    #debug_info_name {
+                        inner: self.inner.get(),
+                    }
+                }
+
+                /// Call `callback` with the current value and every later one, until the
+                /// returned handle is unsubscribed or freed
+                pub fn subscribe(
+                    &self,
+                    #[wasm_bindgen(unchecked_param_type = #debug_info_callback_ts)] callback: ::ankurah::derive_deps::js_sys::Function,
+                ) -> #subscription_name {
+                    use ::ankurah_signals::Subscribe;
+                    let callback = ::ankurah::derive_deps::send_wrapper::SendWrapper::new(callback);
+                    let _ = callback.call1(&JsValue::NULL, &#debug_info_name { inner: self.inner.peek() }.into());
+                    let guard = self.inner.subscribe(move |inner: ::ankurah_virtual_scroll::ScrollDebugInfo| {
+                        let _ = callback.call1(&JsValue::NULL, &#debug_info_name { inner }.into());
+                    });
+                    #subscription_name::new(guard)
+                }
+            }
+
             /// WASM wrapper for ScrollManager
             ///
             /// Manages virtual scroll state and integrates with Ankurah's LiveQuery.
@@ -182,15 +737,80 @@ fn generate_impl(
                     })
                 }
 
-                /// Initialize the scroll manager and populate initial items
+                /// Create a scroll manager from a plain options object
                 ///
-                /// Must be called after construction.
-                #[wasm_bindgen]
-                pub async fn start(&self) -> Result<(), JsValue> {
-                    self.inner.start().await;
-                    Ok(())
+                /// Unset optional fields use the macro defaults, then the builder defaults.
+                #[wasm_bindgen(js_name = withOptions)]
+                pub fn with_options(
+                    ctx: &::ankurah::core::context::Context,
+                    options: #options_name,
+                ) -> Result<#scroll_manager_name, JsValue> {
+                    use ::ankurah::derive_deps::js_sys::Reflect;
+
+                    let get = |key: &str| {
+                        Reflect::get(&options, &JsValue::from_str(key))
+                            .ok()
+                            .filter(|value| !value.is_undefined() && !value.is_null())
+                    };
+                    let number = |key: &str| get(key).and_then(|value| value.as_f64());
+                    let required = |key: &str| {
+                        number(key).ok_or_else(|| JsValue::from_str(&format!("{} is required", key)))
+                    };
+
+                    let order_by = get("orderBy")
+                        .and_then(|value| value.as_string())
+                        .unwrap_or_else(|| #default_order_by.to_string());
+                    let predicate = get("predicate")
+                        .and_then(|value| value.as_string())
+                        .unwrap_or_else(|| #default_predicate.to_string());
+
+                    let mut config = #default_config;
+                    if let Some(buffer_factor) = number("bufferFactor") {
+                        config = config.buffer_factor(buffer_factor);
+                    }
+                    if let Some(buffer_screens) = number("bufferScreens") {
+                        config = config.buffer_screens(buffer_screens);
+                    }
+                    if let Some(live_buffer_screens) = number("liveBufferScreens") {
+                        config = config.live_buffer_screens(live_buffer_screens);
+                    }
+                    if let Some(trigger_screens) = number("triggerScreens") {
+                        config = config.trigger_screens(trigger_screens);
+                    }
+                    if let Some(debounce_items) = number("debounceItems") {
+                        config = config.debounce_items(debounce_items as usize);
+                    }
+                    if let Some(max_window_size) = number("maxWindowSize") {
+                        config = config.max_window_size(max_window_size as usize);
+                    }
+                    if let Some(prefetch_seconds) = number("prefetchSeconds") {
+                        config = config.prefetch_seconds(prefetch_seconds);
+                    }
+                    if let Some(strategy) = get("intersectionRecovery").and_then(|value| value.as_string()) {
+                        let strategy = strategy.parse().map_err(|e: String| JsValue::from_str(&e))?;
+                        config = config.intersection_recovery(strategy);
+                    }
+
+                    let mut builder = ::ankurah_virtual_scroll::ScrollManager::<#view_type>::builder(ctx)
+                        .predicate(predicate.as_str())
+                        .order_by(order_by.as_str())
+                        .viewport(required("viewportHeight")? as u32)
+                        .row_height(#minimum_row_height)
+                        .config(config);
+                    if let Some(start_at) = number("startAt") {
+                        builder = builder.start_at(start_at as i64);
+                    }
+                    let manager = builder
+                        .build()
+                        .map_err(|e| JsValue::from_str(&format!("Failed to create ScrollManager: {:?}", e)))?;
+
+                    Ok(Self {
+                        inner: Rc::new(manager),
+                    })
                 }
 
+                #convenience_constructors
+
                 /// Get the visible set signal
                 ///
                 /// Returns a signal wrapper - call .get() to read current value.
@@ -202,41 +822,76 @@ fn generate_impl(
                     }
                 }
 
-                /// Process a scroll event
-                ///
-                /// # Arguments
-                /// * `first_visible` - EntityId of the first (oldest) visible item
-                /// * `last_visible` - EntityId of the last (newest) visible item
-                /// * `scrolling_backward` - True if user is scrolling toward older items
-                #[wasm_bindgen(js_name = onScroll)]
-                pub fn on_scroll(
-                    &self,
-                    first_visible: String,
-                    last_visible: String,
-                    scrolling_backward: bool,
-                ) {
-                    let first_id: ::ankurah_virtual_scroll::Id = first_visible.parse()
-                        .expect("Invalid first_visible EntityId");
-                    let last_id: ::ankurah_virtual_scroll::Id = last_visible.parse()
-                        .expect("Invalid last_visible EntityId");
-                    self.inner.on_scroll(first_id, last_id, scrolling_backward);
-                }
-
-                /// Get the current scroll mode
-                #[wasm_bindgen(getter)]
-                pub fn mode(&self) -> String {
-                    format!("{:?}", self.inner.mode())
+                /// Get the incremental diff signal (for renderers that apply patches)
+                #[wasm_bindgen(js_name = visibleSetDiff)]
+                pub fn visible_set_diff(&self) -> #diff_signal_name {
+                    #diff_signal_name {
+                        inner: self.inner.visible_set_diff(),
+                    }
+                }
+
+                /// Get the debug info signal (scroll position and buffer state)
+                #[wasm_bindgen(js_name = debugInfo)]
+                pub fn debug_info(&self) -> #debug_info_signal_name {
+                    #debug_info_signal_name {
+                        inner: self.inner.debug_info(),
+                    }
                 }
 
-                /// Get the current selection (predicate + order by) as a string.
-                #[wasm_bindgen(js_name = currentSelection)]
-                pub fn current_selection(&self) -> String {
-                    self.inner.current_selection()
+                /// Viewport edge where the newest items are shown
+                #[wasm_bindgen(getter, js_name = liveEdge)]
+                pub fn live_edge(&self) -> #live_edge_name {
+                    #live_edge
                 }
+
+                #(#forwarded)*
             }
         }
 
         #[cfg(feature = "wasm")]
-        pub use __wasm_scroll_manager::*;
+        pub use #module_name::*;
+    }
+}
+
+/// `#[wasm_bindgen]` method forwarding to the wrapped `ScrollManager`
+fn forward_method(method: &Method, mode_name: &Ident) -> TokenStream {
+    let name = method.ident();
+    let docs = method.doc_attrs();
+    let js_name = format_ident!("{}", method.js_name);
+    let attr = match (method.getter, method.js_name == method.name) {
+        (true, true) => quote!(#[wasm_bindgen(getter)]),
+        (true, false) => quote!(#[wasm_bindgen(getter, js_name = #js_name)]),
+        (false, true) => quote!(#[wasm_bindgen]),
+        (false, false) => quote!(#[wasm_bindgen(js_name = #js_name)]),
+    };
+    let params = method.params.iter().map(|param| {
+        let ident = param.ident();
+        let ty = match param.ty {
+            ParamType::EntityId | ParamType::Predicate => quote!(String),
+            ParamType::Bool => quote!(bool),
+            // JS numbers: u64 would cross as a BigInt
+            ParamType::U32 | ParamType::Millis => quote!(u32),
+            ParamType::F64 => quote!(f64),
+        };
+        quote!(#ident: #ty)
+    });
+    let call = method.core_call(quote!(self.inner), methods::convert_param);
+    let (output, body) = match (method.returns, method.is_async) {
+        // Async methods return a Promise, which needs a Result
+        (Returns::Unit, true) => (quote!(-> Result<(), JsValue>), quote!(#call; Ok(()))),
+        (Returns::Unit, false) => (quote!(), quote!(#call;)),
+        (Returns::Bool, _) => (quote!(-> bool), call),
+        (Returns::String, _) => (quote!(-> String), call),
+        (Returns::Mode, _) => (quote!(-> #mode_name), quote!(#call.into())),
+        (Returns::Result, _) => (quote!(-> Result<(), JsValue>), quote!(#call.map_err(|e| scroll_error_to_js(&e)))),
+    };
+    let async_ = if method.is_async { quote!(async) } else { quote!() };
+
+    quote! {
+        #docs
+        #attr
+        pub #async_ fn #name(&self, #(#params),*) #output {
+            #body
+        }
     }
 }
@@ -0,0 +1,28 @@
+//! Compile-time validation of `generate_scroll_manager!` and `derive(VirtualScroll)` arguments
+//!
+//! Bless changed diagnostics with `TRYBUILD=overwrite cargo test -p ankurah-virtual-scroll-derive`.
+
+#[test]
+fn timestamp_field() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/valid_timestamp_field.rs");
+    t.compile_fail("tests/ui/unknown_timestamp_field.rs");
+    t.compile_fail("tests/ui/invalid_timestamp_field.rs");
+    // Only i64 fields: the generated start_at options are i64
+    t.compile_fail("tests/ui/timestamp_field_must_be_i64.rs");
+    t.compile_fail("tests/ui/i32_timestamp_field_unsupported.rs");
+    t.compile_fail("tests/ui/unknown_argument.rs");
+    t.compile_fail("tests/ui/derive_unknown_timestamp_field.rs");
+    t.compile_fail("tests/ui/derive_timestamp_field_must_be_i64.rs");
+}
+
+#[test]
+fn options() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/options.rs");
+    t.compile_fail("tests/ui/unknown_option.rs");
+    t.compile_fail("tests/ui/duplicate_option.rs");
+    t.compile_fail("tests/ui/invalid_live_edge.rs");
+    t.compile_fail("tests/ui/invalid_name.rs");
+    t.compile_fail("tests/ui/empty_name_prefix.rs");
+}
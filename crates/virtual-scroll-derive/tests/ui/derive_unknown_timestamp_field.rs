@@ -0,0 +1,10 @@
+use ankurah_virtual_scroll_derive::VirtualScroll;
+
+#[derive(VirtualScroll)]
+#[virtual_scroll(timestamp_field = "sent_at")]
+pub struct Message {
+    pub text: String,
+    pub timestamp: i64,
+}
+
+fn main() {}
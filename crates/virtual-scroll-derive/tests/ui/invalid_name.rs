@@ -0,0 +1,15 @@
+// The generated wrappers are gated on the bindings crate's `uniffi` / `wasm` features
+#![allow(unexpected_cfgs)]
+
+use ankurah::Model;
+use serde::{Deserialize, Serialize};
+
+#[derive(Model, Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    pub text: String,
+    pub timestamp: i64,
+}
+
+ankurah_virtual_scroll::generate_scroll_manager!(Message, MessageView, MessageLiveQuery, timestamp_field = "timestamp", name = Chat);
+
+fn main() {}
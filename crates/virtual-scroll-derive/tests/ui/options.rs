@@ -0,0 +1,36 @@
+// The generated wrappers are gated on the bindings crate's `uniffi` / `wasm` features
+#![allow(unexpected_cfgs)]
+
+use ankurah::Model;
+use serde::{Deserialize, Serialize};
+
+#[derive(Model, Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    pub text: String,
+    pub timestamp: i64,
+}
+
+ankurah_virtual_scroll::generate_scroll_manager!(
+    Message,
+    MessageView,
+    MessageLiveQuery,
+    timestamp_field = "timestamp",
+    default_order = "timestamp ASC",
+    default_predicate = "text != ''",
+    min_row_height = 40,
+    buffer_factor = 1.5,
+    live_edge = top,
+    name = FeedScrollManager,
+);
+
+// A second manager for the same model, for another screen
+ankurah_virtual_scroll::generate_scroll_manager!(
+    Message,
+    MessageView,
+    MessageLiveQuery,
+    timestamp_field = "timestamp",
+    buffer_factor = 3,
+    name = ChatScrollManager,
+);
+
+fn main() {}
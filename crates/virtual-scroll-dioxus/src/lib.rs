@@ -0,0 +1,252 @@
+//! Virtual Scroll for Dioxus - a `VirtualList` component driving a `ScrollManager`
+//!
+//! `use_visible_set` bridges the manager's `visible_set` signal into a Dioxus signal.
+//! `VirtualList` renders it, reports the first and last items on screen (with direction
+//! and speed) on every scroll event, keeps the intersection item in place when a slide
+//! lands, and sticks to the bottom while the manager asks for auto-scroll. Rows are laid
+//! out from the heights reported by `onresize`, so it works on every Dioxus renderer with
+//! resize and mounted-element support (web, desktop, mobile).
+//!
+//! ```ignore
+//! #[component]
+//! fn Messages(manager: ReadSignal<Manager>) -> Element {
+//!     rsx! {
+//!         VirtualList {
+//!             manager: manager().0,
+//!             height: 600,
+//!             row: |message: MessageView| rsx! { p { "{message.text().unwrap_or_default()}" } },
+//!         }
+//!     }
+//! }
+//! ```
+
+pub mod list_layout;
+
+use ankurah::model::View;
+use ankurah_virtual_scroll::ankurah_signals::{Peek, Subscribe};
+use ankurah_virtual_scroll::{ScrollManager, VisibleSet};
+use dioxus::html::geometry::PixelsVector2D;
+use dioxus::core::Task;
+use dioxus::prelude::*;
+use futures::StreamExt;
+use list_layout::ListLayout;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+use web_time::Instant;
+
+/// The manager's visible set as a Dioxus signal
+///
+/// Re-subscribes when a render passes a different manager; the subscription ends when the
+/// component unmounts.
+pub fn use_visible_set<V>(manager: &Arc<ScrollManager<V>>) -> ReadSignal<VisibleSet<V>>
+where V: View + Clone + Send + Sync + 'static {
+    let mut visible = use_signal(|| manager.visible_set().peek());
+    let subscribed = use_hook(|| Rc::new(RefCell::new(None::<(Arc<ScrollManager<V>>, Task)>)));
+    let mut subscribed = subscribed.borrow_mut();
+    if subscribed.as_ref().is_some_and(|(current, _)| Arc::ptr_eq(current, manager)) {
+        return visible.into();
+    }
+
+    // The listener may run on any thread; the task applies updates on the UI thread
+    let (tx, mut rx) = futures::channel::mpsc::unbounded();
+    if let Some((_, task)) = subscribed.take() {
+        task.cancel();
+        // The signal can't be written during render: the task shows the new window
+        let _ = tx.unbounded_send(manager.visible_set().peek());
+    }
+    let subscription = manager.visible_set().subscribe(move |vs: VisibleSet<V>| {
+        let _ = tx.unbounded_send(vs);
+    });
+    let task = spawn(async move {
+        let _subscription = subscription;
+        while let Some(vs) = rx.next().await {
+            visible.set(vs);
+        }
+    });
+    *subscribed = Some((manager.clone(), task));
+    visible.into()
+}
+
+#[derive(Props, Clone)]
+pub struct VirtualListProps<V: View + Clone + Send + Sync + 'static> {
+    /// Manager whose visible set is rendered
+    pub manager: Arc<ScrollManager<V>>,
+    /// Renders one item
+    pub row: Callback<V, Element>,
+    /// Viewport height in pixels (the manager's `viewport_height`)
+    #[props(default)]
+    pub height: Option<u32>,
+    /// Height in pixels of the spacers at either end of the window
+    #[props(default = 40)]
+    pub spacer_height: u32,
+    /// Height in pixels assumed for rows not yet measured
+    #[props(default = 40)]
+    pub estimated_row_height: u32,
+    /// CSS class of the scroll container
+    #[props(default, into)]
+    pub class: String,
+}
+
+impl<V: View + Clone + Send + Sync + 'static> PartialEq for VirtualListProps<V> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.manager, &other.manager)
+            && self.row == other.row
+            && self.height == other.height
+            && self.spacer_height == other.spacer_height
+            && self.estimated_row_height == other.estimated_row_height
+            && self.class == other.class
+    }
+}
+
+/// The props a `ListLayout` is built from
+#[derive(Clone)]
+struct LayoutProps<V: View + Clone + Send + Sync + 'static> {
+    manager: Arc<ScrollManager<V>>,
+    estimated_row_height: u32,
+    spacer_height: u32,
+    height: Option<u32>,
+}
+
+impl<V: View + Clone + Send + Sync + 'static> LayoutProps<V> {
+    fn of(props: &VirtualListProps<V>) -> Self {
+        Self {
+            manager: props.manager.clone(),
+            estimated_row_height: props.estimated_row_height,
+            spacer_height: props.spacer_height,
+            height: props.height,
+        }
+    }
+
+    fn layout(&self) -> ListLayout {
+        ListLayout::new(
+            self.estimated_row_height as f64,
+            self.spacer_height as f64,
+            self.height.unwrap_or_default() as f64,
+        )
+    }
+}
+
+impl<V: View + Clone + Send + Sync + 'static> PartialEq for LayoutProps<V> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.manager, &other.manager)
+            && self.estimated_row_height == other.estimated_row_height
+            && self.spacer_height == other.spacer_height
+            && self.height == other.height
+    }
+}
+
+/// A virtualized list of the items in a `ScrollManager`'s window
+///
+/// Each item is wrapped in a `div` with `data-item-id` (its base64 EntityId). While items
+/// precede or follow the window, spacers (`data-spacer="preceding"` / `"following"`)
+/// leave room to keep scrolling until the slide lands; they carry `aria-busy` while it's
+/// in flight.
+///
+/// The caller owns the manager: create and `start()` it, and size the list to its
+/// viewport height (`height`, or CSS through `class`). Unmounting unsubscribes but
+/// doesn't close the manager.
+#[allow(non_snake_case)]
+pub fn VirtualList<V: View + Clone + Send + Sync + 'static>(props: VirtualListProps<V>) -> Element {
+    let visible = use_visible_set(&props.manager);
+    let mut container = use_signal(|| None::<Rc<MountedData>>);
+    let layout_props = LayoutProps::of(&props);
+    let layout = use_hook(|| Rc::new(RefCell::new(layout_props.layout())));
+    let laid_out_for = use_hook(|| Rc::new(RefCell::new(layout_props.clone())));
+    let origin = use_hook(Instant::now);
+
+    // Another manager or row geometry: lay the current window out from scratch
+    if *laid_out_for.borrow() != layout_props {
+        let mut fresh = layout_props.layout();
+        let target = fresh.update(&visible.peek());
+        *layout.borrow_mut() = fresh;
+        *laid_out_for.borrow_mut() = layout_props;
+        scroll_container(container, target);
+    }
+
+    // Lay out each new visible set, then keep its intersection item in place (or follow
+    // the live edge) once the rows are in the DOM
+    use_effect({
+        let layout = layout.clone();
+        move || {
+            let target = layout.borrow_mut().update(&visible.read());
+            scroll_container(container, target);
+        }
+    });
+
+    let onmounted = {
+        let layout = layout.clone();
+        move |event: MountedEvent| {
+            container.set(Some(event.data()));
+            let scroll_top = layout.borrow().scroll_top();
+            scroll_container(container, Some(scroll_top).filter(|top| *top > 0.0));
+        }
+    };
+    let onresize = {
+        let layout = layout.clone();
+        move |event: ResizeEvent| {
+            if let Ok(size) = event.data().get_content_box_size() {
+                scroll_container(container, layout.borrow_mut().viewport_resized(size.height));
+            }
+        }
+    };
+    let onscroll = {
+        let layout = layout.clone();
+        let manager = props.manager.clone();
+        move |event: ScrollEvent| {
+            let data = event.data();
+            let time_ms = origin.elapsed().as_secs_f64() * 1000.0;
+            let scrolled = layout.borrow_mut().scrolled(data.scroll_top(), data.client_height() as f64, time_ms);
+            if let Some((first, last, sample)) = scrolled {
+                manager.on_scroll_with_velocity(first, last, sample.scrolling_backward, sample.velocity);
+            }
+        }
+    };
+
+    let vs = visible.read();
+    let style = format!(
+        "overflow-y: auto; overflow-anchor: none;{}",
+        props.height.map(|h| format!(" height: {}px;", h)).unwrap_or_default()
+    );
+    let spacer_style = format!("height: {}px;", props.spacer_height);
+    let rows = vs.items.iter().cloned().map(|item| {
+        let id = item.entity().id();
+        let layout = layout.clone();
+        let onresize = move |event: ResizeEvent| {
+            if let Ok(size) = event.data().get_border_box_size() {
+                scroll_container(container, layout.borrow_mut().row_resized(id, size.height));
+            }
+        };
+        rsx! {
+            div { key: "{id}", "data-item-id": id.to_base64(), onresize, {props.row.call(item)} }
+        }
+    });
+
+    rsx! {
+        div { class: props.class, style, onmounted, onresize, onscroll,
+            if vs.has_more_preceding {
+                div {
+                    "data-spacer": "preceding",
+                    "aria-busy": (vs.loading_preceding || vs.initial_loading).to_string(),
+                    style: spacer_style.clone(),
+                }
+            }
+            {rows}
+            if vs.has_more_following {
+                div {
+                    "data-spacer": "following",
+                    "aria-busy": vs.loading_following.to_string(),
+                    style: spacer_style,
+                }
+            }
+        }
+    }
+}
+
+/// Scroll the container to `target`, if there is one and the container is mounted
+fn scroll_container(container: Signal<Option<Rc<MountedData>>>, target: Option<f64>) {
+    let (Some(top), Some(container)) = (target, container.peek().clone()) else { return };
+    spawn(async move {
+        let _ = container.scroll(PixelsVector2D::new(0.0, top), ScrollBehavior::Instant).await;
+    });
+}
@@ -0,0 +1,154 @@
+//! List Layout Module
+//!
+//! Row geometry and scroll bookkeeping behind the Dioxus `VirtualList`. Dioxus renderers
+//! report sizes through `onresize` rather than DOM queries, so rows are laid out from
+//! their measured heights (an estimate until measured).
+
+use ankurah::{model::View, EntityId};
+use ankurah_virtual_scroll::layout::{stack, visible_range, ItemRect, LayoutSnapshot, ScrollSample, ScrollTracker};
+use ankurah_virtual_scroll::VisibleSet;
+use std::collections::{HashMap, HashSet};
+
+/// Scroll changes smaller than this aren't applied
+const MIN_ADJUSTMENT_PX: f64 = 1.0;
+
+/// Layout of the rendered window and the scroll position within it
+///
+/// Methods returning `Option<f64>` give the scroll position the list should move to; the
+/// move is already recorded, so the scroll event it causes isn't reported as the user's.
+#[derive(Clone, Debug)]
+pub struct ListLayout {
+    estimated_row_height: f64,
+    spacer_height: f64,
+    rows: Vec<EntityId>,
+    heights: HashMap<EntityId, f64>,
+    leading_spacer: bool,
+    trailing_spacer: bool,
+    /// Stick to the bottom as rows are added or measured (auto-scroll at the live edge)
+    follow_bottom: bool,
+    scroll_top: f64,
+    viewport_height: f64,
+    tracker: ScrollTracker,
+}
+
+impl ListLayout {
+    pub fn new(estimated_row_height: f64, spacer_height: f64, viewport_height: f64) -> Self {
+        Self {
+            estimated_row_height,
+            spacer_height,
+            rows: Vec::new(),
+            heights: HashMap::new(),
+            leading_spacer: false,
+            trailing_spacer: false,
+            follow_bottom: false,
+            scroll_top: 0.0,
+            viewport_height,
+            tracker: ScrollTracker::new(),
+        }
+    }
+
+    /// Rects of the rendered rows, below the leading spacer if shown
+    pub fn rects(&self) -> Vec<ItemRect> {
+        let top = if self.leading_spacer { self.spacer_height } else { 0.0 };
+        stack(self.rows.iter().map(|id| self.row_height(id)), top)
+    }
+
+    /// Height of the scroll content, spacers included
+    pub fn content_height(&self) -> f64 {
+        let spacers = (self.leading_spacer as u8 + self.trailing_spacer as u8) as f64 * self.spacer_height;
+        spacers + self.rows.iter().map(|id| self.row_height(id)).sum::<f64>()
+    }
+
+    pub fn scroll_top(&self) -> f64 {
+        self.scroll_top
+    }
+
+    /// Lay out a new visible set, keeping its intersection item in place or following the
+    /// bottom while the manager asks for auto-scroll
+    pub fn update<V: View>(&mut self, visible_set: &VisibleSet<V>) -> Option<f64> {
+        let snapshot = LayoutSnapshot::from_items(self.rows.iter().copied().zip(self.rects().iter().map(|r| r.top)));
+
+        self.rows = visible_set.items.iter().map(|item| item.entity().id()).collect();
+        let rendered: HashSet<EntityId> = self.rows.iter().copied().collect();
+        self.heights.retain(|id, _| rendered.contains(id));
+        self.leading_spacer = visible_set.has_more_preceding;
+        self.trailing_spacer = visible_set.has_more_following;
+        self.follow_bottom = visible_set.should_auto_scroll && visible_set.intersection.is_none();
+
+        let target = match &visible_set.intersection {
+            Some(intersection) => {
+                let anchor = intersection.entity_id;
+                let index = self.rows.iter().position(|id| *id == anchor)?;
+                snapshot.anchored_scroll_top(&anchor, self.rects()[index].top, self.scroll_top)
+            }
+            None if self.follow_bottom => Some(self.bottom()),
+            None => None,
+        };
+        self.scroll_to(target)
+    }
+
+    /// Record a row's measured height
+    ///
+    /// A row above the viewport changing height would shift everything below it, so the
+    /// scroll position moves by the difference.
+    pub fn row_resized(&mut self, id: EntityId, height: f64) -> Option<f64> {
+        let index = self.rows.iter().position(|row| *row == id)?;
+        let rect = self.rects()[index];
+        self.heights.insert(id, height);
+
+        if self.follow_bottom {
+            self.scroll_to(Some(self.bottom()))
+        } else if rect.top + rect.height <= self.scroll_top {
+            self.scroll_to(Some(self.scroll_top + height - rect.height))
+        } else {
+            None
+        }
+    }
+
+    /// Record the viewport's height
+    pub fn viewport_resized(&mut self, height: f64) -> Option<f64> {
+        self.viewport_height = height;
+        if self.follow_bottom {
+            self.scroll_to(Some(self.bottom()))
+        } else {
+            None
+        }
+    }
+
+    /// Record a scroll event at `time_ms`; the first and last visible rows and the sample to
+    /// report, unless the event was caused by the list or nothing is on screen
+    pub fn scrolled(
+        &mut self,
+        scroll_top: f64,
+        viewport_height: f64,
+        time_ms: f64,
+    ) -> Option<(EntityId, EntityId, ScrollSample)> {
+        self.scroll_top = scroll_top;
+        self.viewport_height = viewport_height;
+        let sample = self.tracker.sample(scroll_top, time_ms)?;
+
+        // The user left the bottom: stop following it until the next update asks again
+        self.follow_bottom &= scroll_top >= self.bottom() - MIN_ADJUSTMENT_PX;
+
+        let (first, last) = visible_range(&self.rects(), scroll_top, viewport_height)?;
+        Some((self.rows[first], self.rows[last], sample))
+    }
+
+    fn row_height(&self, id: &EntityId) -> f64 {
+        self.heights.get(id).copied().unwrap_or(self.estimated_row_height)
+    }
+
+    fn bottom(&self) -> f64 {
+        (self.content_height() - self.viewport_height).max(0.0)
+    }
+
+    fn scroll_to(&mut self, target: Option<f64>) -> Option<f64> {
+        let target = target?;
+        if (target - self.scroll_top).abs() < MIN_ADJUSTMENT_PX {
+            return None;
+        }
+        self.scroll_top = target;
+        self.tracker.adjusted(target);
+        Some(target)
+    }
+}
@@ -0,0 +1,317 @@
+//! Test utilities for ankurah-virtual-scroll-dioxus integration tests
+//!
+//! `Harness` renders a `VirtualList` in a Dioxus `VirtualDom` without a browser: it
+//! records the listeners and `data-item-id`s the list creates, dispatches scroll, resize
+//! and mounted events to them, and records the scroll positions the list asks for.
+#![allow(unused_imports)]
+#![allow(dead_code)]
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+// Workaround for https://github.com/ankurah/ankurah/issues/211
+use wasm_bindgen::prelude::*;
+
+use ankurah::policy::DEFAULT_CONTEXT;
+use ankurah::{Context, Model, Node, PermissiveAgent};
+use ankurah_storage_sled::SledStorageEngine;
+use ankurah_virtual_scroll_dioxus::VirtualList;
+use dioxus::core::{AttributeValue, ElementId, Template, WriteMutations};
+use dioxus::html::geometry::{PixelsSize, PixelsVector2D};
+use dioxus::html::*;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use ankurah::error::MutationError;
+pub use ankurah::EntityId;
+pub use ankurah_virtual_scroll::{ScrollManager, ScrollMode, VisibleSet};
+
+/// Test message model
+#[derive(Model, Debug, Clone, Serialize, Deserialize)]
+pub struct TestMessage {
+    pub timestamp: i64,
+}
+
+/// Create a durable sled-backed context for testing
+pub async fn durable_sled_setup() -> Result<Context, anyhow::Error> {
+    let node = Node::new_durable(
+        Arc::new(SledStorageEngine::new_test().unwrap()),
+        PermissiveAgent::new(),
+    );
+    node.system.create().await?;
+    Ok(node.context_async(DEFAULT_CONTEXT).await)
+}
+
+/// Create test messages in a single transaction
+pub async fn create_messages(
+    ctx: &Context,
+    timestamps: impl IntoIterator<Item = i64>,
+) -> Result<Vec<EntityId>, MutationError> {
+    let trx = ctx.begin();
+    let mut ids = Vec::new();
+    for timestamp in timestamps {
+        let msg = trx.create(&TestMessage { timestamp }).await?;
+        ids.push(msg.id());
+    }
+    trx.commit().await?;
+    Ok(ids)
+}
+
+// ============================================================================
+// VirtualDom harness
+// ============================================================================
+
+/// Row height of the rendered messages, and the list's estimate for unmeasured rows
+pub const ROW_HEIGHT: f64 = 50.0;
+pub const VIEWPORT_HEIGHT: f64 = 500.0;
+pub const SPACER_HEIGHT: f64 = 40.0;
+
+#[derive(Props, Clone)]
+struct AppProps {
+    /// Manager passed to the list on the next render
+    manager: Rc<RefCell<Arc<ScrollManager<TestMessageView>>>>,
+    /// Timestamps passed to `row`, in call order
+    rows: Rc<RefCell<Vec<i64>>>,
+}
+
+impl PartialEq for AppProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.manager, &other.manager) && Rc::ptr_eq(&self.rows, &other.rows)
+    }
+}
+
+#[allow(non_snake_case)]
+fn App(props: AppProps) -> Element {
+    let rows = props.rows.clone();
+    let manager = props.manager.borrow().clone();
+    rsx! {
+        VirtualList {
+            manager,
+            height: VIEWPORT_HEIGHT as u32,
+            estimated_row_height: ROW_HEIGHT as u32,
+            class: "messages",
+            row: move |message: TestMessageView| {
+                let timestamp = message.timestamp().unwrap();
+                rows.borrow_mut().push(timestamp);
+                rsx! { p { "ts:{timestamp}" } }
+            },
+        }
+    }
+}
+
+/// Listeners and `data-item-id`s created by the VirtualDom
+#[derive(Default)]
+struct Recorder {
+    listeners: Vec<(&'static str, ElementId)>,
+    item_ids: HashMap<ElementId, String>,
+}
+
+impl WriteMutations for Recorder {
+    fn append_children(&mut self, _: ElementId, _: usize) {}
+    fn assign_node_id(&mut self, _: &'static [u8], _: ElementId) {}
+    fn create_placeholder(&mut self, _: ElementId) {}
+    fn create_text_node(&mut self, _: &str, _: ElementId) {}
+    fn load_template(&mut self, _: Template, _: usize, _: ElementId) {}
+    fn replace_node_with(&mut self, _: ElementId, _: usize) {}
+    fn replace_placeholder_with_nodes(&mut self, _: &'static [u8], _: usize) {}
+    fn insert_nodes_after(&mut self, _: ElementId, _: usize) {}
+    fn insert_nodes_before(&mut self, _: ElementId, _: usize) {}
+    fn set_attribute(&mut self, name: &'static str, _: Option<&'static str>, value: &AttributeValue, id: ElementId) {
+        if let ("data-item-id", AttributeValue::Text(value)) = (name, value) {
+            self.item_ids.insert(id, value.clone());
+        }
+    }
+    fn set_node_text(&mut self, _: &str, _: ElementId) {}
+    fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.listeners.push((name, id));
+    }
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.listeners.retain(|listener| *listener != (name, id));
+    }
+    fn remove_node(&mut self, _: ElementId) {}
+    fn push_root(&mut self, _: ElementId) {}
+}
+
+/// Mounted scroll container that records the positions it's scrolled to
+#[derive(Clone, Default)]
+struct TestElement {
+    scrolls: Rc<RefCell<Vec<f64>>>,
+}
+
+impl RenderedElementBacking for TestElement {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scroll(
+        &self,
+        coordinates: PixelsVector2D,
+        _behavior: ScrollBehavior,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        self.scrolls.borrow_mut().push(coordinates.y);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// The serialized converter, with mounted events carrying a `TestElement`
+struct TestConverter;
+
+macro_rules! serialized {
+    ($($method:ident -> $data:ty),* $(,)?) => {
+        $(fn $method(&self, event: &PlatformEventData) -> $data {
+            SerializedHtmlEventConverter.$method(event)
+        })*
+    };
+}
+
+impl HtmlEventConverter for TestConverter {
+    serialized! {
+        convert_animation_data -> AnimationData,
+        convert_cancel_data -> CancelData,
+        convert_clipboard_data -> ClipboardData,
+        convert_composition_data -> CompositionData,
+        convert_drag_data -> DragData,
+        convert_focus_data -> FocusData,
+        convert_form_data -> FormData,
+        convert_image_data -> ImageData,
+        convert_keyboard_data -> KeyboardData,
+        convert_media_data -> MediaData,
+        convert_mouse_data -> MouseData,
+        convert_pointer_data -> PointerData,
+        convert_resize_data -> ResizeData,
+        convert_scroll_data -> ScrollData,
+        convert_selection_data -> SelectionData,
+        convert_toggle_data -> ToggleData,
+        convert_touch_data -> TouchData,
+        convert_transition_data -> TransitionData,
+        convert_visible_data -> VisibleData,
+        convert_wheel_data -> WheelData,
+    }
+
+    fn convert_mounted_data(&self, event: &PlatformEventData) -> MountedData {
+        MountedData::new(event.downcast::<TestElement>().cloned().unwrap())
+    }
+}
+
+/// A `VirtualList` of `TestMessageView`s in a VirtualDom
+pub struct Harness {
+    dom: VirtualDom,
+    recorder: Recorder,
+    manager: Rc<RefCell<Arc<ScrollManager<TestMessageView>>>>,
+    rows: Rc<RefCell<Vec<i64>>>,
+    rendered: Vec<i64>,
+    container: TestElement,
+}
+
+impl Harness {
+    /// Render the list and mount its container
+    pub async fn new(manager: Arc<ScrollManager<TestMessageView>>) -> Self {
+        set_event_converter(Box::new(TestConverter));
+        let rows = Rc::new(RefCell::new(Vec::new()));
+        let manager = Rc::new(RefCell::new(manager));
+        let dom = VirtualDom::new_with_props(App, AppProps { manager: manager.clone(), rows: rows.clone() });
+        let mut harness = Self {
+            dom,
+            recorder: Recorder::default(),
+            manager,
+            rows,
+            rendered: Vec::new(),
+            container: TestElement::default(),
+        };
+        harness.dom.rebuild(&mut harness.recorder);
+        harness.take_rendered();
+
+        let container = harness.container.clone();
+        harness.dispatch("mounted", container, harness.container_id());
+        harness.settle().await;
+        harness
+    }
+
+    /// Run tasks and re-render until nothing changes for 50ms
+    pub async fn settle(&mut self) {
+        for _ in 0..100 {
+            if tokio::time::timeout(Duration::from_millis(50), self.dom.wait_for_work()).await.is_err() {
+                return;
+            }
+            self.dom.render_immediate(&mut self.recorder);
+            self.take_rendered();
+        }
+        panic!("VirtualDom did not settle");
+    }
+
+    /// Re-render the list with another manager
+    pub async fn set_manager(&mut self, manager: Arc<ScrollManager<TestMessageView>>) {
+        *self.manager.borrow_mut() = manager;
+        self.dom.mark_dirty(ScopeId::APP);
+        self.dom.render_immediate(&mut self.recorder);
+        self.take_rendered();
+        self.settle().await;
+    }
+
+    /// Timestamps of the rows in the last render, in render order
+    pub fn rendered(&self) -> Vec<i64> {
+        self.rendered.clone()
+    }
+
+    /// Scroll positions the list asked for, oldest first
+    pub fn scrolls(&self) -> Vec<f64> {
+        self.container.scrolls.borrow().clone()
+    }
+
+    /// Scroll the container to `scroll_top`
+    pub async fn scroll(&mut self, scroll_top: f64) {
+        let data = SerializedScrollData {
+            scroll_top,
+            scroll_left: 0.0,
+            scroll_width: 0,
+            scroll_height: 0,
+            client_width: 0,
+            client_height: VIEWPORT_HEIGHT as i32,
+        };
+        self.dispatch("scroll", data, self.container_id());
+        self.settle().await;
+    }
+
+    /// Report a row's measured height
+    pub async fn resize_row(&mut self, id: EntityId, height: f64) {
+        let element = self
+            .recorder
+            .item_ids
+            .iter()
+            .find(|(_, item_id)| **item_id == id.to_base64())
+            .map(|(element, _)| *element)
+            .expect("row not rendered");
+        let size = PixelsSize::new(0.0, height);
+        self.dispatch("resize", SerializedResizeData::new(size, size), element);
+        self.settle().await;
+    }
+
+    fn container_id(&self) -> ElementId {
+        self.recorder
+            .listeners
+            .iter()
+            .find(|(name, _)| *name == "scroll")
+            .map(|(_, id)| *id)
+            .expect("no scroll listener")
+    }
+
+    fn dispatch(&mut self, name: &str, data: impl Any, element: ElementId) {
+        let event = Event::new(Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>, false);
+        self.dom.runtime().handle_event(name, event, element);
+        self.dom.process_events();
+    }
+
+    /// Keep the rows passed to `row` by the latest render that rendered any
+    fn take_rendered(&mut self) {
+        let rows = std::mem::take(&mut *self.rows.borrow_mut());
+        if !rows.is_empty() {
+            self.rendered = rows;
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! VirtualList tests in a Dioxus VirtualDom
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30).
+//! Rows are laid out below a 40px leading spacer while older items exist.
+
+mod common;
+
+use ankurah::model::View as _;
+use ankurah_virtual_scroll::ankurah_signals::Peek;
+use common::*;
+use std::sync::Arc;
+
+type Manager = Arc<ScrollManager<TestMessageView>>;
+
+async fn setup(count: i64) -> Result<(ankurah::Context, Manager), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..count).map(|i| 1000 + i)).await?;
+    let manager = Arc::new(ScrollManager::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?);
+    Ok((ctx, manager))
+}
+
+fn timestamps(manager: &Manager) -> Vec<i64> {
+    manager.visible_set().peek().items.iter().map(|item| item.timestamp().unwrap()).collect()
+}
+
+/// Scroll position of the bottom of a window of `rows` rows below the leading spacer
+fn bottom(rows: usize) -> f64 {
+    SPACER_HEIGHT + rows as f64 * ROW_HEIGHT - VIEWPORT_HEIGHT
+}
+
+/// The live window renders oldest first and the list scrolls to the bottom.
+#[tokio::test]
+async fn test_renders_live_window() -> Result<(), anyhow::Error> {
+    let (_ctx, manager) = setup(100).await?;
+    manager.start().await;
+
+    let harness = Harness::new(manager.clone()).await;
+    assert_eq!(harness.rendered(), (1070..=1099).collect::<Vec<_>>());
+    assert_eq!(harness.scrolls().last(), Some(&bottom(30)));
+
+    Ok(())
+}
+
+/// The Dioxus signal follows the manager: a list mounted before start() renders the
+/// live window once it loads, and new items at the live edge keep it at the bottom.
+#[tokio::test]
+async fn test_follows_visible_set() -> Result<(), anyhow::Error> {
+    let (ctx, manager) = setup(100).await?;
+
+    let mut harness = Harness::new(manager.clone()).await;
+    assert!(harness.rendered().is_empty());
+
+    manager.start().await;
+    harness.settle().await;
+    assert_eq!(harness.rendered(), (1070..=1099).collect::<Vec<_>>());
+
+    create_messages(&ctx, [1100]).await?;
+    harness.settle().await;
+    let rendered = harness.rendered();
+    assert_eq!(rendered, timestamps(&manager));
+    assert_eq!(rendered.last(), Some(&1100));
+    assert_eq!(harness.scrolls().last(), Some(&bottom(rendered.len())));
+
+    Ok(())
+}
+
+/// Scrolling up reports the visible rows; the slide keeps the intersection item in place,
+/// and the scroll event that causes isn't reported back.
+#[tokio::test]
+async fn test_scroll_slides_and_restores_anchor() -> Result<(), anyhow::Error> {
+    let (_ctx, manager) = setup(200).await?;
+    manager.start().await;
+    let mut harness = Harness::new(manager.clone()).await;
+    let before = harness.rendered();
+
+    // Rows 8-17 (1178-1187) on screen, 8 rows above: inside the trigger threshold
+    harness.scroll(440.0).await;
+    assert_eq!(manager.mode(), ScrollMode::Backward);
+    let vs = manager.visible_set().peek();
+    let after = timestamps(&manager);
+    assert_eq!(harness.rendered(), after);
+    assert!(after[0] < before[0]);
+
+    // The intersection item is where it was on screen
+    let anchor = vs.intersection.as_ref().expect("slide has an intersection").entity_id;
+    let anchor_timestamp = vs.items.iter().find(|item| item.entity().id() == anchor).unwrap().timestamp()?;
+    let old_index = before.iter().position(|ts| *ts == anchor_timestamp).unwrap();
+    let new_index = after.iter().position(|ts| *ts == anchor_timestamp).unwrap();
+    let corrected = 440.0 + (new_index as f64 - old_index as f64) * ROW_HEIGHT;
+    assert_eq!(harness.scrolls().last(), Some(&corrected));
+
+    let update_count = manager.debug_info().peek().update_count;
+    harness.scroll(corrected).await;
+    assert_eq!(manager.debug_info().peek().update_count, update_count);
+    assert!(!manager.debug_info().peek().update_pending);
+
+    Ok(())
+}
+
+/// A row above the viewport measuring taller than estimated shifts the scroll position by
+/// the difference; rows on screen don't.
+#[tokio::test]
+async fn test_row_resize_keeps_position() -> Result<(), anyhow::Error> {
+    let (_ctx, manager) = setup(200).await?;
+    manager.start().await;
+    let mut harness = Harness::new(manager.clone()).await;
+
+    // Leave the bottom so the list stops following it; 17 rows above, so no slide
+    let before = harness.rendered();
+    harness.scroll(900.0).await;
+    assert_eq!(harness.rendered(), before);
+    let scrolls = harness.scrolls().len();
+
+    let items = manager.visible_set().peek().items;
+    harness.resize_row(items[0].entity().id(), 80.0).await;
+    assert_eq!(harness.scrolls()[scrolls..], [930.0]);
+
+    // Row 20 (top 1040) is on screen
+    harness.resize_row(items[20].entity().id(), 80.0).await;
+    assert_eq!(harness.scrolls().len(), scrolls + 1);
+
+    Ok(())
+}
+
+/// Passing another manager re-subscribes: the list renders its window and follows its
+/// updates, and no longer the first manager's.
+#[tokio::test]
+async fn test_switches_manager() -> Result<(), anyhow::Error> {
+    let (first_ctx, first) = setup(100).await?;
+    first.start().await;
+    let mut harness = Harness::new(first.clone()).await;
+    // Leave the bottom of the first window
+    harness.scroll(900.0).await;
+    let scrolls = harness.scrolls().len();
+
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..40).map(|i| 2000 + i)).await?;
+    let second = Arc::new(ScrollManager::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?);
+    second.start().await;
+
+    harness.set_manager(second.clone()).await;
+    assert_eq!(harness.rendered(), (2010..=2039).collect::<Vec<_>>());
+    // Laid out afresh: following the live edge of the new window
+    assert_eq!(harness.scrolls()[scrolls..].last(), Some(&bottom(30)));
+
+    create_messages(&ctx, [2040]).await?;
+    harness.settle().await;
+    assert_eq!(harness.rendered().last(), Some(&2040));
+
+    create_messages(&first_ctx, [1100]).await?;
+    harness.settle().await;
+    assert_eq!(harness.rendered().last(), Some(&2040));
+    assert_eq!(harness.rendered(), timestamps(&second));
+
+    Ok(())
+}
@@ -0,0 +1,213 @@
+//! Virtual Scroll for Leptos - a `<VirtualList>` component driving a `ScrollManager`
+//!
+//! The component renders the manager's visible set, reports the first and last items on
+//! screen (with direction and speed) on every scroll event, keeps the intersection item in
+//! place when a slide lands, and sticks to the bottom while the manager asks for
+//! auto-scroll. Items are rendered oldest first (newest at the bottom).
+//!
+//! ```ignore
+//! let manager = Arc::new(
+//!     ScrollManager::<MessageView>::builder(&ctx)
+//!         .predicate("room = 'general'")
+//!         .order_by("timestamp DESC")
+//!         .row_height(40)
+//!         .viewport(600)
+//!         .build()?,
+//! );
+//! leptos::task::spawn_local({
+//!     let manager = manager.clone();
+//!     async move { manager.start().await }
+//! });
+//!
+//! view! {
+//!     <VirtualList
+//!         manager=manager
+//!         height=600
+//!         row=|message: MessageView| view! { <p>{message.text().unwrap_or_default()}</p> }
+//!     />
+//! }
+//! ```
+
+pub mod list_layout;
+
+use ankurah::{model::View, EntityId};
+use ankurah_virtual_scroll::ankurah_signals::{Peek, Subscribe};
+use ankurah_virtual_scroll::layout::ItemRect;
+use ankurah_virtual_scroll::{ScrollManager, VisibleSet};
+use leptos::html::Div;
+use list_layout::ListLayout;
+use leptos::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+pub use ankurah_virtual_scroll::layout::{self, ScrollSample};
+
+/// Selects the item wrappers among the container's children
+const ITEM_SELECTOR: &str = ":scope > [data-item-id]";
+
+/// Renderer state shared by the scroll handler, the post-render effect and the
+/// visible set subscription
+#[derive(Default)]
+struct ListState {
+    layout: ListLayout,
+    /// Bumped for items updated in place, so their rows are re-rendered
+    revisions: HashMap<EntityId, u64>,
+}
+
+/// A virtualized list of the items in a `ScrollManager`'s window
+///
+/// Each item is wrapped in a `<div data-item-id="{base64 EntityId}">`. While items precede
+/// or follow the window, spacers (`data-spacer="preceding"` / `"following"`) leave room to
+/// keep scrolling until the slide lands; they carry `aria-busy` while it's in flight.
+///
+/// The caller owns the manager: create and `start()` it, and size the list to its
+/// viewport height (`height`, or CSS through `class`). Unmounting unsubscribes but
+/// doesn't close the manager.
+#[component]
+pub fn VirtualList<V, R, IV>(
+    /// Manager whose visible set is rendered
+    manager: Arc<ScrollManager<V>>,
+    /// Renders one item
+    row: R,
+    /// Viewport height in pixels (the manager's `viewport_height`)
+    #[prop(optional)]
+    height: Option<u32>,
+    /// Height in pixels of the spacers at either end of the window
+    #[prop(default = 40)]
+    spacer_height: u32,
+    /// CSS class of the scroll container
+    #[prop(optional, into)]
+    class: Option<String>,
+) -> impl IntoView
+where
+    V: View + Clone + Send + Sync + 'static,
+    R: Fn(V) -> IV + Send + Sync + Clone + 'static,
+    IV: IntoView + 'static,
+{
+    let container = NodeRef::<Div>::new();
+    let state = Arc::new(Mutex::new(ListState::default()));
+    let visible = ArcRwSignal::new(manager.visible_set().peek());
+
+    let subscription = manager.visible_set().subscribe({
+        let state = state.clone();
+        let visible = visible.clone();
+        move |vs: VisibleSet<V>| {
+            {
+                let mut state = state.lock().unwrap();
+                for id in &vs.updated {
+                    *state.revisions.entry(*id).or_default() += 1;
+                }
+                let ids: HashSet<EntityId> = vs.items.iter().map(|item| item.entity().id()).collect();
+                state.revisions.retain(|id, _| ids.contains(id));
+            }
+            visible.set(vs);
+        }
+    });
+    on_cleanup(move || drop(subscription));
+
+    // After each render: keep the intersection item in place, or follow the live edge,
+    // then record the new layout for the next update
+    Effect::new({
+        let state = state.clone();
+        let visible = visible.clone();
+        move |_| {
+            let (anchor, should_auto_scroll) =
+                visible.with(|vs| (vs.intersection.as_ref().map(|i| i.entity_id), vs.should_auto_scroll));
+            let state = state.clone();
+            request_animation_frame(move || {
+                let Some(el) = container.get_untracked() else { return };
+                let items = rendered_items(&el);
+                let mut state = state.lock().unwrap();
+                let (scroll_top, scroll_height) = (el.scroll_top() as f64, el.scroll_height() as f64);
+                if let Some(target) = state.layout.rendered(&items, anchor, should_auto_scroll, scroll_top, scroll_height) {
+                    el.set_scroll_top(target as i32);
+                    state.layout.adjusted(el.scroll_top() as f64);
+                }
+            });
+        }
+    });
+
+    let on_scroll = {
+        let state = state.clone();
+        move |ev: web_sys::Event| {
+            let Some(el) = container.get_untracked() else { return };
+            let items = rendered_items(&el);
+            let scrolled = state.lock().unwrap().layout.scrolled(
+                &items,
+                el.scroll_top() as f64,
+                el.client_height() as f64,
+                ev.time_stamp(),
+            );
+            if let Some((first, last, sample)) = scrolled {
+                manager.on_scroll_with_velocity(first, last, sample.scrolling_backward, sample.velocity);
+            }
+        }
+    };
+
+    let rows = {
+        let state = state.clone();
+        let visible = visible.clone();
+        move || {
+            let revisions = state.lock().unwrap().revisions.clone();
+            visible.with(|vs| {
+                vs.items
+                    .iter()
+                    .map(|item| {
+                        let id = item.entity().id();
+                        (id, revisions.get(&id).copied().unwrap_or_default(), item.clone())
+                    })
+                    .collect::<Vec<_>>()
+            })
+        }
+    };
+
+    let style = format!(
+        "overflow-y: auto; overflow-anchor: none; position: relative;{}",
+        height.map(|h| format!(" height: {}px;", h)).unwrap_or_default()
+    );
+    let preceding_style = format!("height: {}px;", spacer_height);
+    let following_style = preceding_style.clone();
+    let more_preceding = Signal::derive({
+        let visible = visible.clone();
+        move || visible.with(|vs| vs.has_more_preceding)
+    });
+    let more_following = Signal::derive({
+        let visible = visible.clone();
+        move || visible.with(|vs| vs.has_more_following)
+    });
+    let loading_preceding = Signal::derive({
+        let visible = visible.clone();
+        move || visible.with(|vs| vs.loading_preceding || vs.initial_loading)
+    });
+    let loading_following = Signal::derive(move || visible.with(|vs| vs.loading_following));
+
+    view! {
+        <div node_ref=container class=class.unwrap_or_default() style=style on:scroll=on_scroll>
+            <Show when=move || more_preceding.get()>
+                <div data-spacer="preceding" aria-busy=move || loading_preceding.get().to_string() style=preceding_style.clone()></div>
+            </Show>
+            <For
+                each=rows
+                key=|(id, revision, _)| (*id, *revision)
+                children=move |(id, _, item)| view! { <div data-item-id=id.to_base64()>{row(item)}</div> }
+            />
+            <Show when=move || more_following.get()>
+                <div data-spacer="following" aria-busy=move || loading_following.get().to_string() style=following_style.clone()></div>
+            </Show>
+        </div>
+    }
+}
+
+/// Rendered item wrappers, in render order, with their offsets in the container
+fn rendered_items(container: &HtmlElement) -> Vec<(EntityId, ItemRect)> {
+    let Ok(nodes) = container.query_selector_all(ITEM_SELECTOR) else { return Vec::new() };
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i)?.dyn_into::<HtmlElement>().ok())
+        .filter_map(|el| {
+            let id = EntityId::from_base64(el.get_attribute("data-item-id")?).ok()?;
+            Some((id, ItemRect { top: el.offset_top() as f64, height: el.offset_height() as f64 }))
+        })
+        .collect()
+}
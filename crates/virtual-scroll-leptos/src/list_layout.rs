@@ -0,0 +1,71 @@
+//! List Layout Module
+//!
+//! Scroll bookkeeping behind the Leptos `VirtualList`, kept apart from the DOM: the
+//! component measures the rendered item wrappers and hands their rects in.
+
+use ankurah::EntityId;
+use ankurah_virtual_scroll::layout::{visible_range, ItemRect, LayoutSnapshot, ScrollSample, ScrollTracker};
+
+/// Scroll changes smaller than this aren't applied
+const MIN_ADJUSTMENT_PX: f64 = 1.0;
+
+/// The layout last rendered and the scroll events seen since
+///
+/// `items` are the rendered item wrappers in render order, with their offsets in the
+/// scroll container.
+#[derive(Clone, Debug, Default)]
+pub struct ListLayout {
+    tracker: ScrollTracker,
+    snapshot: LayoutSnapshot,
+}
+
+impl ListLayout {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a render; the scroll position that keeps `anchor` in place, or the bottom
+    /// while following the live edge
+    ///
+    /// The browser clamps the position it is given, so the caller records where the list
+    /// ended up with `adjusted`.
+    pub fn rendered(
+        &mut self,
+        items: &[(EntityId, ItemRect)],
+        anchor: Option<EntityId>,
+        should_auto_scroll: bool,
+        scroll_top: f64,
+        scroll_height: f64,
+    ) -> Option<f64> {
+        let target = match anchor {
+            Some(anchor) => items
+                .iter()
+                .find(|(id, _)| *id == anchor)
+                .and_then(|(id, rect)| self.snapshot.anchored_scroll_top(id, rect.top, scroll_top)),
+            None if should_auto_scroll => Some(scroll_height),
+            None => None,
+        };
+        self.snapshot = LayoutSnapshot::from_items(items.iter().map(|(id, rect)| (*id, rect.top)));
+        target.filter(|target| (target - scroll_top).abs() >= MIN_ADJUSTMENT_PX)
+    }
+
+    /// Record a scroll position set by the list rather than the user
+    pub fn adjusted(&mut self, scroll_top: f64) {
+        self.tracker.adjusted(scroll_top);
+    }
+
+    /// Record a scroll event at `time_ms`; the first and last visible items and the sample
+    /// to report, unless the event was caused by the list or nothing is on screen
+    pub fn scrolled(
+        &mut self,
+        items: &[(EntityId, ItemRect)],
+        scroll_top: f64,
+        viewport_height: f64,
+        time_ms: f64,
+    ) -> Option<(EntityId, EntityId, ScrollSample)> {
+        let sample = self.tracker.sample(scroll_top, time_ms)?;
+        let rects: Vec<ItemRect> = items.iter().map(|(_, rect)| *rect).collect();
+        let (first, last) = visible_range(&rects, scroll_top, viewport_height)?;
+        Some((items[first].0, items[last].0, sample))
+    }
+}
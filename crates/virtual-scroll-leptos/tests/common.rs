@@ -0,0 +1,48 @@
+//! Test utilities for ankurah-virtual-scroll-leptos integration tests
+#![allow(unused_imports)]
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+// Workaround for https://github.com/ankurah/ankurah/issues/211
+use wasm_bindgen::prelude::*;
+
+use ankurah::policy::DEFAULT_CONTEXT;
+use ankurah::{Context, Model, Node, PermissiveAgent};
+use ankurah_storage_sled::SledStorageEngine;
+use serde::{Deserialize, Serialize};
+
+pub use ankurah::error::MutationError;
+pub use ankurah::EntityId;
+pub use ankurah_virtual_scroll::{ScrollManager, VisibleSet};
+
+/// Test message model
+#[derive(Model, Debug, Clone, Serialize, Deserialize)]
+pub struct TestMessage {
+    pub timestamp: i64,
+}
+
+/// Create a durable sled-backed context for testing
+pub async fn durable_sled_setup() -> Result<Context, anyhow::Error> {
+    let node = Node::new_durable(
+        Arc::new(SledStorageEngine::new_test().unwrap()),
+        PermissiveAgent::new(),
+    );
+    node.system.create().await?;
+    Ok(node.context_async(DEFAULT_CONTEXT).await)
+}
+
+/// Create test messages in a single transaction
+pub async fn create_messages(
+    ctx: &Context,
+    timestamps: impl IntoIterator<Item = i64>,
+) -> Result<Vec<EntityId>, MutationError> {
+    let trx = ctx.begin();
+    let mut ids = Vec::new();
+    for timestamp in timestamps {
+        let msg = trx.create(&TestMessage { timestamp }).await?;
+        ids.push(msg.id());
+    }
+    trx.commit().await?;
+    Ok(ids)
+}
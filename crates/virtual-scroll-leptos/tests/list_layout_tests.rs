@@ -0,0 +1,77 @@
+//! Scroll handling tests for VirtualList, driven through ListLayout as the component's
+//! `on:scroll` handler and post-render effect do
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30),
+//! rendered below a 40px spacer.
+
+mod common;
+
+use ankurah::model::View as _;
+use ankurah_virtual_scroll::ankurah_signals::Peek;
+use ankurah_virtual_scroll::layout::{stack, ItemRect};
+use ankurah_virtual_scroll::ScrollMode;
+use ankurah_virtual_scroll_leptos::list_layout::ListLayout;
+use common::*;
+
+const SPACER_PX: f64 = 40.0;
+const VIEWPORT_PX: f64 = 500.0;
+
+/// Item wrappers as the browser would lay out `vs`, with their offsets
+fn layout(vs: &VisibleSet<TestMessageView>) -> Vec<(EntityId, ItemRect)> {
+    let top = if vs.has_more_preceding { SPACER_PX } else { 0.0 };
+    let rects = stack(vs.items.iter().map(|_| 50.0), top);
+    vs.items.iter().map(|item| item.entity().id()).zip(rects).collect()
+}
+
+/// Height of the scroll content for `items`, the trailing spacer included
+fn scroll_height(vs: &VisibleSet<TestMessageView>, items: &[(EntityId, ItemRect)]) -> f64 {
+    let bottom = items.last().map_or(0.0, |(_, rect)| rect.top + rect.height);
+    bottom + if vs.has_more_following { SPACER_PX } else { 0.0 }
+}
+
+/// Following the live edge before any scroll event, then scrolling up: the first user
+/// event is reported as backward and slides the window, the anchor correction keeps the
+/// intersection item where it was on screen, and its scroll event isn't reported.
+#[tokio::test]
+async fn test_scroll_reports_and_anchor_correction() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..100).map(|i| 1000 + i)).await?;
+    let manager = ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?;
+    manager.start().await;
+    let mut list = ListLayout::new();
+
+    // Initial render: scroll to the bottom, which the browser clamps
+    let vs = manager.visible_set().peek();
+    let items = layout(&vs);
+    let height = scroll_height(&vs, &items);
+    assert_eq!(height, 1540.0);
+    assert_eq!(list.rendered(&items, None, vs.should_auto_scroll, 0.0, height), Some(1540.0));
+    let bottom = height - VIEWPORT_PX;
+    list.adjusted(bottom);
+
+    // The browser may coalesce that adjustment's scroll event with the user's: the first
+    // event seen is up 500px, with items 10..=19 (1080..=1089) on screen
+    let (first, last, sample) = list.scrolled(&items, 540.0, VIEWPORT_PX, 0.0).expect("user scroll is reported");
+    assert_eq!((first, last), (vs.items[10].entity().id(), vs.items[19].entity().id()));
+    assert!(sample.scrolling_backward);
+    manager.on_scroll_with_velocity(first, last, sample.scrolling_backward, sample.velocity);
+
+    let vs = manager.next_settled().await;
+    assert_eq!(manager.mode(), ScrollMode::Backward);
+    let anchor = vs.intersection.as_ref().expect("slide has an intersection").entity_id;
+    let added = vs.items.iter().filter(|item| item.timestamp().unwrap() < 1070).count();
+    assert_eq!(added, 20);
+
+    // The older items render above the anchor: scroll down by their height
+    let items = layout(&vs);
+    let height = scroll_height(&vs, &items);
+    let target = 540.0 + added as f64 * 50.0;
+    assert_eq!(list.rendered(&items, Some(anchor), vs.should_auto_scroll, 540.0, height), Some(target));
+    list.adjusted(target);
+    assert_eq!(list.scrolled(&items, target, VIEWPORT_PX, 10.0), None);
+
+    // Re-rendering the same window keeps the position
+    assert_eq!(list.rendered(&items, Some(anchor), vs.should_auto_scroll, target, height), None);
+
+    Ok(())
+}
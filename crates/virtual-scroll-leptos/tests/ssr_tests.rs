@@ -0,0 +1,117 @@
+//! Server-side rendering tests for VirtualList
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30).
+
+mod common;
+
+use ankurah::model::View as _;
+use ankurah_virtual_scroll::ankurah_signals::Peek;
+use ankurah_virtual_scroll_leptos::VirtualList;
+use common::*;
+use leptos::prelude::*;
+use std::sync::Arc;
+use std::time::Duration;
+
+type Manager = Arc<ScrollManager<TestMessageView>>;
+
+async fn setup(count: i64) -> Result<Manager, anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..count).map(|i| 1000 + i)).await?;
+    Ok(Arc::new(ScrollManager::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?))
+}
+
+fn render(manager: &Manager) -> String {
+    Owner::new().with(|| {
+        view! {
+            <VirtualList
+                manager=manager.clone()
+                height=500
+                class="messages"
+                row=|message: TestMessageView| view! { <p>{format!("ts:{}", message.timestamp().unwrap())}</p> }
+            />
+        }
+        .to_html()
+    })
+}
+
+/// Timestamps of the rendered rows, in render order
+fn rendered_timestamps(html: &str) -> Vec<i64> {
+    html.split("ts:").skip(1).map(|s| s[..s.find('<').unwrap()].parse().unwrap()).collect()
+}
+
+/// Wait for the in-flight slide to land
+async fn settle(manager: &Manager) {
+    for _ in 0..50 {
+        if !manager.debug_info().peek().update_pending {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("slide did not land within 500ms");
+}
+
+/// The live window renders oldest first, one wrapper per item, with a spacer above.
+#[tokio::test]
+async fn test_renders_live_window() -> Result<(), anyhow::Error> {
+    let manager = setup(100).await?;
+    manager.start().await;
+
+    let html = render(&manager);
+    assert_eq!(rendered_timestamps(&html), (1070..=1099).collect::<Vec<_>>());
+
+    let items = manager.visible_set().peek().items;
+    assert_eq!(html.matches("data-item-id=").count(), 30);
+    assert!(html.contains(&format!("data-item-id=\"{}\"", items[0].entity().id().to_base64())));
+
+    assert!(html.contains("data-spacer=\"preceding\""));
+    assert!(!html.contains("data-spacer=\"following\""));
+    assert!(html.contains("class=\"messages\""));
+    assert!(html.contains("height: 500px;"));
+    assert!(html.contains("overflow-anchor: none;"));
+
+    Ok(())
+}
+
+/// Before start() the list is empty and the leading spacer shows the initial load.
+#[tokio::test]
+async fn test_renders_initial_loading() -> Result<(), anyhow::Error> {
+    let manager = setup(10).await?;
+
+    let html = render(&manager);
+    assert!(rendered_timestamps(&html).is_empty());
+    assert!(html.contains("data-spacer=\"preceding\" aria-busy=\"true\""));
+
+    Ok(())
+}
+
+/// A window that holds every item has no spacers.
+#[tokio::test]
+async fn test_renders_without_spacers() -> Result<(), anyhow::Error> {
+    let manager = setup(10).await?;
+    manager.start().await;
+
+    let html = render(&manager);
+    assert_eq!(rendered_timestamps(&html), (1000..=1009).collect::<Vec<_>>());
+    assert!(!html.contains("data-spacer"));
+
+    Ok(())
+}
+
+/// Mid-history, spacers render at both ends of the window.
+#[tokio::test]
+async fn test_renders_mid_history() -> Result<(), anyhow::Error> {
+    let manager = setup(200).await?;
+    manager.start().await;
+
+    let vs = manager.visible_set().peek();
+    manager.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    settle(&manager).await;
+
+    let html = render(&manager);
+    assert_eq!(rendered_timestamps(&html), (1150..=1199).collect::<Vec<_>>());
+    assert!(html.contains("data-spacer=\"preceding\" aria-busy=\"false\""));
+    assert!(html.contains("data-spacer=\"following\" aria-busy=\"false\""));
+    assert!(html.contains("height: 40px;"));
+
+    Ok(())
+}
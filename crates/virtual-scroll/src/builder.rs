@@ -0,0 +1,132 @@
+//! ScrollManager Builder
+//!
+//! Named-option construction for `ScrollManager`. Parse errors and invalid settings are
+//! collected and reported from `build()` instead of panicking.
+
+use ankql::ast::{OrderByItem, Predicate};
+use ankurah::core::value::Value;
+use ankurah::error::RetrievalError;
+use ankurah::{model::View, Context};
+
+use std::sync::Arc;
+
+use crate::{IntoOrderBy, ScrollConfig, ScrollManager, ScrollMetrics};
+
+/// Builder for [`ScrollManager`], created with [`ScrollManager::builder`]
+///
+/// Defaults: predicate `true` (all items), two screens of buffer, starting at the live
+/// edge. `order_by`, `viewport` and `row_height` are required.
+pub struct ScrollManagerBuilder<V: View + Clone + Send + Sync + 'static> {
+    ctx: Context,
+    predicate: Result<Predicate, String>,
+    order_by: Option<Result<Vec<OrderByItem>, String>>,
+    viewport_height: Option<u32>,
+    minimum_row_height: Option<u32>,
+    config: ScrollConfig,
+    start_at: Option<Value>,
+    metrics: Option<Arc<dyn ScrollMetrics>>,
+    _view: std::marker::PhantomData<V>,
+}
+
+impl<V: View + Clone + Send + Sync + 'static> ScrollManagerBuilder<V> {
+    pub(crate) fn new(ctx: &Context) -> Self {
+        Self {
+            ctx: ctx.clone(),
+            predicate: Ok(Predicate::True),
+            order_by: None,
+            viewport_height: None,
+            minimum_row_height: None,
+            config: ScrollConfig::default(),
+            start_at: None,
+            metrics: None,
+            _view: std::marker::PhantomData,
+        }
+    }
+
+    /// Filter predicate (e.g., `"room_id = 'abc'"`)
+    pub fn predicate(mut self, predicate: impl TryInto<Predicate, Error = impl std::fmt::Debug>) -> Self {
+        self.predicate = predicate.try_into().map_err(|e| format!("Failed to parse predicate: {:?}", e));
+        self
+    }
+
+    /// Visual order (e.g., `"timestamp DESC"` for chat)
+    pub fn order_by(mut self, order_by: impl IntoOrderBy) -> Self {
+        self.order_by = Some(order_by.into_order_by());
+        self
+    }
+
+    /// Viewport height in pixels
+    pub fn viewport(mut self, height: u32) -> Self {
+        self.viewport_height = Some(height);
+        self
+    }
+
+    /// Guaranteed minimum item height in pixels
+    pub fn row_height(mut self, minimum_row_height: u32) -> Self {
+        self.minimum_row_height = Some(minimum_row_height);
+        self
+    }
+
+    /// Buffer as multiple of viewport (2.0 = 2x viewport buffer), as in `ScrollManager::new`
+    pub fn buffer(mut self, buffer_factor: f64) -> Self {
+        self.config = self.config.buffer_factor(buffer_factor);
+        self
+    }
+
+    /// Pagination thresholds; replaces any earlier `buffer()` setting
+    pub fn config(mut self, config: ScrollConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Open the window at an item instead of the live edge
+    ///
+    /// `value` is compared against the first ORDER BY field: the initial window holds
+    /// the items at or before it, with the newest of them as the intersection anchor.
+    pub fn start_at(mut self, value: impl Into<Value>) -> Self {
+        self.start_at = Some(value.into());
+        self
+    }
+
+    /// Report pagination events (slide latency, debounced triggers, intersection
+    /// failures, mode changes) to `metrics`; may be shared between managers
+    pub fn metrics(mut self, metrics: Arc<dyn ScrollMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Validate the options and create the ScrollManager
+    pub fn build(self) -> Result<ScrollManager<V>, RetrievalError> {
+        let invalid = |message: String| RetrievalError::Other(message);
+
+        let predicate = self.predicate.map_err(invalid)?;
+        let display_order = self
+            .order_by
+            .ok_or_else(|| invalid("order_by is required".to_string()))?
+            .map_err(invalid)?;
+        if display_order.is_empty() {
+            return Err(invalid("order_by must name at least one field".to_string()));
+        }
+        let viewport_height = match self.viewport_height {
+            Some(height) if height > 0 => height,
+            Some(_) => return Err(invalid("viewport height must be positive".to_string())),
+            None => return Err(invalid("viewport is required".to_string())),
+        };
+        let minimum_row_height = match self.minimum_row_height {
+            Some(height) if height > 0 => height,
+            Some(_) => return Err(invalid("row height must be positive".to_string())),
+            None => return Err(invalid("row_height is required".to_string())),
+        };
+
+        ScrollManager::from_parts(
+            &self.ctx,
+            predicate,
+            display_order,
+            minimum_row_height,
+            viewport_height,
+            self.config,
+            self.start_at,
+            self.metrics,
+        )
+    }
+}
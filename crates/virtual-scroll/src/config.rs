@@ -0,0 +1,186 @@
+//! Scroll Configuration
+//!
+//! Tunable pagination thresholds. Screen-based values are multiples of `screen_items`
+//! (see `windowing.rs`), so they scale with viewport and minimum row height.
+
+use crate::windowing;
+
+/// What to do when a slide's result no longer contains its anchor item
+///
+/// This happens when the anchor is deleted, re-sorted or pushed out of range by
+/// insertions while the slide is in flight.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RecoveryStrategy {
+    /// Emit the result without an intersection and report the failure
+    Report,
+    /// Re-issue the slide once with twice the limit, then fall back to `NearestNeighbor`
+    RetryWider,
+    /// Anchor on the remaining item nearest the missing anchor by sort key
+    #[default]
+    NearestNeighbor,
+    /// Discard the result and reload the live window
+    JumpToLive,
+}
+
+impl std::str::FromStr for RecoveryStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "report" => Ok(Self::Report),
+            "retry_wider" => Ok(Self::RetryWider),
+            "nearest_neighbor" => Ok(Self::NearestNeighbor),
+            "jump_to_live" => Ok(Self::JumpToLive),
+            _ => Err(format!("unknown recovery strategy '{}'", s)),
+        }
+    }
+}
+
+/// Pagination thresholds for a ScrollManager
+///
+/// Defaults reproduce the standard windowing algorithm: trigger at one screen of
+/// remaining buffer, keep two screens of buffer (B = 2S), debounce by one screen of
+/// user scroll distance, no window size cap. Velocity-aware prefetch only applies when
+/// the renderer reports a scroll velocity. Intersection failures re-anchor on the
+/// nearest remaining item.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScrollConfig {
+    /// Trigger pagination when the buffer on the scroll-toward side drops to this many screens
+    pub trigger_screens: f64,
+    /// Items kept beyond the visible area on each side after a slide, in screens
+    pub buffer_screens: f64,
+    /// Buffer used to size the live window, in screens (`None` = `buffer_screens`)
+    pub live_buffer_screens: Option<f64>,
+    /// Items the user must scroll between triggers (`None` = one screen)
+    pub debounce_items: Option<usize>,
+    /// Hard upper bound on window size in items; slides evict from the far side to stay under it
+    pub max_window_size: Option<usize>,
+    /// Seconds of scrolling at the reported velocity to prefetch beyond the trigger/buffer
+    pub prefetch_seconds: f64,
+    /// Recovery applied when a slide's anchor is missing from its result
+    pub intersection_recovery: RecoveryStrategy,
+}
+
+impl Default for ScrollConfig {
+    fn default() -> Self {
+        Self {
+            trigger_screens: 1.0,
+            buffer_screens: 2.0,
+            live_buffer_screens: None,
+            debounce_items: None,
+            max_window_size: None,
+            prefetch_seconds: 0.5,
+            intersection_recovery: RecoveryStrategy::default(),
+        }
+    }
+}
+
+impl ScrollConfig {
+    pub fn trigger_screens(mut self, screens: f64) -> Self {
+        self.trigger_screens = screens;
+        self
+    }
+
+    pub fn buffer_screens(mut self, screens: f64) -> Self {
+        self.buffer_screens = screens;
+        self
+    }
+
+    pub fn live_buffer_screens(mut self, screens: f64) -> Self {
+        self.live_buffer_screens = Some(screens);
+        self
+    }
+
+    /// Apply a `buffer_factor` as `ScrollManager::new` does: it sizes the live window
+    /// (clamped to at least 2.0) and leaves the slide buffer at `buffer_screens`
+    pub fn buffer_factor(self, buffer_factor: f64) -> Self {
+        self.live_buffer_screens(buffer_factor.max(2.0))
+    }
+
+    pub fn debounce_items(mut self, items: usize) -> Self {
+        self.debounce_items = Some(items);
+        self
+    }
+
+    pub fn max_window_size(mut self, items: usize) -> Self {
+        self.max_window_size = Some(items);
+        self
+    }
+
+    pub fn prefetch_seconds(mut self, seconds: f64) -> Self {
+        self.prefetch_seconds = seconds;
+        self
+    }
+
+    pub fn intersection_recovery(mut self, strategy: RecoveryStrategy) -> Self {
+        self.intersection_recovery = strategy;
+        self
+    }
+
+    /// Threshold in screens as used by the windowing formulas (N = live buffer / 2)
+    pub fn threshold_screens(&self) -> f64 {
+        self.live_buffer_screens.unwrap_or(self.buffer_screens) / 2.0
+    }
+
+    /// Remaining buffer (in items) at which pagination triggers
+    pub fn trigger_items(&self, screen_items: usize) -> usize {
+        windowing::min_buffer(screen_items, self.trigger_screens)
+    }
+
+    /// Buffer (in items) kept beyond the visible area: B
+    pub fn buffer_items(&self, screen_items: usize) -> usize {
+        (self.buffer_screens * screen_items as f64).ceil() as usize
+    }
+
+    /// User scroll distance (in items) required between triggers: T
+    pub fn debounce_distance(&self, screen_items: usize) -> usize {
+        self.debounce_items.unwrap_or(screen_items)
+    }
+
+    /// Extra items to prefetch for a scroll velocity (pixels/second): the most items that
+    /// could pass in `prefetch_seconds`, capped at one buffer
+    pub fn prefetch_items(&self, velocity: f64, minimum_row_height: u32, screen_items: usize) -> usize {
+        if !velocity.is_finite() {
+            return 0;
+        }
+        let distance_px = velocity.abs() * self.prefetch_seconds;
+        let items = (distance_px / minimum_row_height.max(1) as f64).ceil() as usize;
+        items.min(self.buffer_items(screen_items))
+    }
+
+    /// Check the configuration against the windowing formulas for a given screen size
+    pub fn validate(&self, screen_items: usize) -> Result<(), String> {
+        if !(self.trigger_screens.is_finite() && self.trigger_screens > 0.0) {
+            return Err(format!("trigger_screens must be positive, got {}", self.trigger_screens));
+        }
+        if !self.buffer_screens.is_finite() || self.buffer_screens <= self.trigger_screens {
+            // After a slide the buffer is B items; at or below the trigger it would re-trigger at once
+            return Err(format!(
+                "buffer_screens ({}) must be greater than trigger_screens ({})",
+                self.buffer_screens, self.trigger_screens
+            ));
+        }
+        if let Some(live_buffer) = self.live_buffer_screens {
+            if !(live_buffer.is_finite() && live_buffer > 0.0) {
+                return Err(format!("live_buffer_screens must be positive, got {}", live_buffer));
+            }
+        }
+        if !(self.prefetch_seconds.is_finite() && self.prefetch_seconds >= 0.0) {
+            return Err(format!("prefetch_seconds must be non-negative, got {}", self.prefetch_seconds));
+        }
+        if self.debounce_items == Some(0) {
+            return Err("debounce_items must be at least 1".to_string());
+        }
+        if let Some(max) = self.max_window_size {
+            // The live window (one screen plus one buffer) must fit; slides evict trailing buffer
+            let live_window = windowing::live_window_size(screen_items, self.threshold_screens());
+            if max < live_window {
+                return Err(format!(
+                    "max_window_size ({}) must be at least the live window size ({})",
+                    max, live_window
+                ));
+            }
+        }
+        Ok(())
+    }
+}
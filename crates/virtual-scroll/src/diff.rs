@@ -0,0 +1,163 @@
+//! Visible Set Diffing
+//!
+//! Computes incremental patches between successive visible sets, so renderers that
+//! apply patches (RecyclerView, FlatList, keyed lists) don't need to receive and diff
+//! the full item vector on every emission.
+
+use std::collections::{HashMap, HashSet};
+
+use ankurah::model::View;
+use ankurah_proto::EntityId;
+
+/// A single patch operation against the previously emitted window
+///
+/// Indices are positional: each one refers to the list as it stands after all
+/// preceding changes in the same [`VisibleSetDiff`] have been applied.
+#[derive(Clone, Debug)]
+pub enum VisibleSetChange<V> {
+    /// Contiguous run of items removed, starting at `index`
+    Remove { index: usize, ids: Vec<EntityId> },
+    /// Item stayed in the window but changed position (`Vec::remove(from)` then `Vec::insert(to)`)
+    Move { from: usize, to: usize, id: EntityId },
+    /// Contiguous run of items inserted, starting at `index`
+    Insert { index: usize, items: Vec<V> },
+    /// Item content changed in place
+    Update { index: usize, item: V },
+}
+
+/// Ordered list of changes that turns the previous visible set into the current one
+///
+/// Changes are listed in application order: removals (highest index first), then moves,
+/// then insertions (lowest index first), then in-place updates at their final index.
+#[derive(Clone, Debug)]
+pub struct VisibleSetDiff<V> {
+    pub changes: Vec<VisibleSetChange<V>>,
+}
+
+impl<V> Default for VisibleSetDiff<V> {
+    fn default() -> Self {
+        Self { changes: Vec::new() }
+    }
+}
+
+impl<V> VisibleSetDiff<V> {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl<V: View + Clone> VisibleSetDiff<V> {
+    /// Compute the diff between two windows, keyed by EntityId
+    ///
+    /// `updated` lists entities whose content changed (from the LiveQuery `ChangeSet`);
+    /// those that remain in the window and were not freshly inserted produce `Update` changes.
+    pub fn between(old: &[V], new: &[V], updated: &[EntityId]) -> Self {
+        let new_index: HashMap<EntityId, usize> = new
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (item.entity().id(), i))
+            .collect();
+        let old_ids: HashSet<EntityId> = old.iter().map(|item| item.entity().id()).collect();
+
+        let mut changes = Vec::new();
+
+        // Removals, highest index first so earlier indices stay valid
+        let mut working: Vec<EntityId> = old.iter().map(|item| item.entity().id()).collect();
+        let mut i = working.len();
+        while i > 0 {
+            i -= 1;
+            if new_index.contains_key(&working[i]) {
+                continue;
+            }
+            let end = i + 1;
+            while i > 0 && !new_index.contains_key(&working[i - 1]) {
+                i -= 1;
+            }
+            let ids: Vec<EntityId> = working.drain(i..end).collect();
+            changes.push(VisibleSetChange::Remove { index: i, ids });
+        }
+
+        // Survivors whose target indices form the longest increasing run stay put; the rest move
+        let targets: Vec<usize> = working.iter().map(|id| new_index[id]).collect();
+        let stable = longest_increasing_subsequence(&targets);
+        let mut placed: Vec<bool> = stable;
+        let mut moved: Vec<(usize, EntityId)> = working
+            .iter()
+            .zip(&placed)
+            .filter(|(_, &is_stable)| !is_stable)
+            .map(|(id, _)| (new_index[id], *id))
+            .collect();
+        moved.sort_by_key(|(target, _)| *target);
+
+        let mut placed_targets: Vec<usize> = targets;
+        for (target, id) in moved {
+            let from = working.iter().position(|w| *w == id).expect("moved item is in working list");
+            working.remove(from);
+            placed_targets.remove(from);
+            placed.remove(from);
+            // Directly after the last placed item that precedes this one in the new window.
+            // Items not yet placed all have larger targets, so they can't be skipped over wrongly.
+            let to = placed_targets
+                .iter()
+                .zip(&placed)
+                .rposition(|(&t, &is_placed)| is_placed && t < target)
+                .map_or(0, |p| p + 1);
+            working.insert(to, id);
+            placed_targets.insert(to, target);
+            placed.insert(to, true);
+            changes.push(VisibleSetChange::Move { from, to, id });
+        }
+
+        // Insertions, lowest index first; everything before `index` is already in place
+        let mut i = 0;
+        while i < new.len() {
+            if old_ids.contains(&new[i].entity().id()) {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < new.len() && !old_ids.contains(&new[i].entity().id()) {
+                i += 1;
+            }
+            changes.push(VisibleSetChange::Insert { index: start, items: new[start..i].to_vec() });
+        }
+
+        // In-place updates for surviving items, at their final index
+        let mut update_indices: Vec<usize> = updated
+            .iter()
+            .filter(|id| old_ids.contains(id))
+            .filter_map(|id| new_index.get(id).copied())
+            .collect();
+        update_indices.sort_unstable();
+        update_indices.dedup();
+        for index in update_indices {
+            changes.push(VisibleSetChange::Update { index, item: new[index].clone() });
+        }
+
+        Self { changes }
+    }
+}
+
+/// Mark the members of one longest strictly increasing subsequence of `values`
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<bool> {
+    // tails[k] = index into `values` of the smallest tail of an increasing run of length k+1
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev: Vec<Option<usize>> = vec![None; values.len()];
+    for (i, &v) in values.iter().enumerate() {
+        let k = tails.partition_point(|&t| values[t] < v);
+        prev[i] = if k > 0 { Some(tails[k - 1]) } else { None };
+        if k == tails.len() {
+            tails.push(i);
+        } else {
+            tails[k] = i;
+        }
+    }
+
+    let mut members = vec![false; values.len()];
+    let mut cursor = tails.last().copied();
+    while let Some(i) = cursor {
+        members[i] = true;
+        cursor = prev[i];
+    }
+    members
+}
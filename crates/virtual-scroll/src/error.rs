@@ -0,0 +1,57 @@
+//! Scroll Errors
+//!
+//! Failures reported on `VisibleSet::error`, so platform layers can choose the right
+//! UI (retry, jump to live, show a message) without parsing strings.
+
+use ankurah_proto::EntityId;
+use std::time::Duration;
+
+use crate::LoadDirection;
+
+/// A failure surfaced on the visible set
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScrollError {
+    /// A slide's anchor was missing from its result and could not be recovered
+    IntersectionFailed { anchor: EntityId, direction: LoadDirection },
+    /// The livequery rejected a slide's selection
+    QueryFailed(String),
+    /// The cursor item's ORDER BY value could not be used in a query
+    CursorConversionFailed(String),
+    /// The manager was used in a state that doesn't allow the operation
+    InvalidState(String),
+    /// `start_with_timeout` gave up waiting for the initial query result
+    Timeout(Duration),
+    /// The manager was closed, so the operation was cancelled
+    Closed,
+}
+
+impl ScrollError {
+    /// Stable name of the variant, as exposed by the generated wrappers
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ScrollError::IntersectionFailed { .. } => "intersectionFailed",
+            ScrollError::QueryFailed(_) => "queryFailed",
+            ScrollError::CursorConversionFailed(_) => "cursorConversionFailed",
+            ScrollError::InvalidState(_) => "invalidState",
+            ScrollError::Timeout(_) => "timeout",
+            ScrollError::Closed => "closed",
+        }
+    }
+}
+
+impl std::fmt::Display for ScrollError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScrollError::IntersectionFailed { anchor, direction } => {
+                write!(f, "Intersection failed: anchor {} not found in {:?} result", anchor, direction)
+            }
+            ScrollError::QueryFailed(message) => write!(f, "Query failed: {}", message),
+            ScrollError::CursorConversionFailed(message) => write!(f, "Cursor conversion failed: {}", message),
+            ScrollError::InvalidState(message) => write!(f, "Invalid state: {}", message),
+            ScrollError::Timeout(after) => write!(f, "Timed out after {}ms waiting for the initial result", after.as_millis()),
+            ScrollError::Closed => write!(f, "Scroll manager closed"),
+        }
+    }
+}
+
+impl std::error::Error for ScrollError {}
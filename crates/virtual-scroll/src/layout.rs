@@ -0,0 +1,136 @@
+//! Layout Module
+//!
+//! Renderer-side helpers shared by the framework components (`VirtualList` for Leptos and
+//! Dioxus): which items overlap the viewport, which way the user is scrolling, and where
+//! to scroll so the intersection item stays in place. None of it touches the DOM.
+
+use ankurah_proto::EntityId;
+use std::collections::HashMap;
+
+/// Scroll movements within this many pixels of an adjustment are attributed to it
+const ADJUSTMENT_TOLERANCE_PX: f64 = 1.0;
+
+/// Vertical extent of a rendered item, relative to the top of the scroll content
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ItemRect {
+    pub top: f64,
+    pub height: f64,
+}
+
+/// Rects of rows of the given heights stacked from `top`, for renderers that measure row
+/// heights rather than offsets
+pub fn stack(heights: impl IntoIterator<Item = f64>, top: f64) -> Vec<ItemRect> {
+    let mut next = top;
+    heights
+        .into_iter()
+        .map(|height| {
+            let rect = ItemRect { top: next, height };
+            next += height;
+            rect
+        })
+        .collect()
+}
+
+/// Indices of the first and last items overlapping the viewport
+///
+/// `items` must be in render order. Returns `None` when no item is on screen.
+pub fn visible_range(items: &[ItemRect], scroll_top: f64, viewport_height: f64) -> Option<(usize, usize)> {
+    let viewport_bottom = scroll_top + viewport_height;
+    let mut visible = items
+        .iter()
+        .enumerate()
+        .filter(|(_, rect)| rect.top + rect.height > scroll_top && rect.top < viewport_bottom)
+        .map(|(index, _)| index);
+    let first = visible.next()?;
+    Some((first, visible.next_back().unwrap_or(first)))
+}
+
+/// A user scroll event, as reported to `ScrollManager::on_scroll_with_velocity`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollSample {
+    /// True if the user is scrolling toward older items (up)
+    pub scrolling_backward: bool,
+    /// Scroll speed in pixels per second
+    pub velocity: f64,
+}
+
+/// Derives direction and speed from successive scroll positions
+///
+/// Scroll events caused by the list itself (anchor correction, auto-scroll) are recorded
+/// with `adjusted` and not reported, so they can't trigger a slide.
+#[derive(Clone, Debug, Default)]
+pub struct ScrollTracker {
+    last_top: Option<f64>,
+    last_time: Option<f64>,
+    scrolling_backward: bool,
+    adjusted: Option<f64>,
+}
+
+impl ScrollTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a scroll event at `time_ms`; `None` if it was caused by an adjustment
+    pub fn sample(&mut self, scroll_top: f64, time_ms: f64) -> Option<ScrollSample> {
+        let last_top = self.last_top.replace(scroll_top);
+        let last_time = self.last_time.replace(time_ms);
+        if let Some(adjusted) = self.adjusted.take() {
+            if (scroll_top - adjusted).abs() <= ADJUSTMENT_TOLERANCE_PX {
+                return None;
+            }
+        }
+
+        // An unmoved position (e.g. a resize) keeps the previous direction
+        if let Some(last_top) = last_top.filter(|top| *top != scroll_top) {
+            self.scrolling_backward = scroll_top < last_top;
+        }
+        let velocity = match (last_top, last_time) {
+            (Some(last_top), Some(last_time)) if time_ms > last_time => {
+                (scroll_top - last_top).abs() / (time_ms - last_time) * 1000.0
+            }
+            _ => 0.0,
+        };
+        Some(ScrollSample { scrolling_backward: self.scrolling_backward, velocity })
+    }
+
+    /// Record a scroll position set by the list rather than the user
+    ///
+    /// It becomes the baseline for the next event's direction, so the first user scroll
+    /// after the initial scroll to the bottom is reported as backward.
+    pub fn adjusted(&mut self, scroll_top: f64) {
+        self.adjusted = Some(scroll_top);
+        self.last_top = Some(scroll_top);
+    }
+}
+
+/// Item offsets as last rendered, to keep the intersection item in place across an update
+#[derive(Clone, Debug, Default)]
+pub struct LayoutSnapshot {
+    tops: HashMap<EntityId, f64>,
+}
+
+impl LayoutSnapshot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of rendered items and their offsets
+    pub fn from_items(items: impl IntoIterator<Item = (EntityId, f64)>) -> Self {
+        Self { tops: items.into_iter().collect() }
+    }
+
+    /// Offset of `id` when the snapshot was taken
+    pub fn top(&self, id: &EntityId) -> Option<f64> {
+        self.tops.get(id).copied()
+    }
+
+    /// Scroll position that keeps `anchor` where it was on screen, given its new offset
+    ///
+    /// Items inserted above the anchor push it down by the height they add; scrolling by
+    /// the same amount cancels the jump. `None` if the anchor wasn't rendered before.
+    pub fn anchored_scroll_top(&self, anchor: &EntityId, new_top: f64, scroll_top: f64) -> Option<f64> {
+        let old_top = self.top(anchor)?;
+        Some((scroll_top + new_top - old_top).max(0.0))
+    }
+}
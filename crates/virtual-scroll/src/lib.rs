@@ -1,21 +1,40 @@
 //! Virtual Scroll - Ankurah-integrated virtual scroll state machine
 
+pub mod builder;
+pub mod config;
+pub mod diff;
+pub mod error;
+pub mod layout;
+pub mod metrics;
+pub mod stream;
 pub mod windowing;
 
 use ankql::ast::{
     ComparisonOperator, Expr, Literal, OrderByItem, OrderDirection, PathExpr, Predicate, Selection,
 };
-use ankurah::changes::ChangeSet;
-use ankurah::core::selection::filter::Filterable;
-use ankurah::core::value::Value;
+use ankurah::changes::{ChangeSet, ItemChange};
+use ankurah::core::selection::filter::{evaluate_predicate, Filterable};
+use ankurah::core::value::{Value, ValueType};
 use ankurah::{model::View, Context, LiveQuery};
 use ankurah_proto::EntityId;
 use ankurah_signals::{Mut, Peek, Read, Subscribe};
+use futures::channel::{mpsc, oneshot};
+use futures::future::{Either, FutureExt, Shared};
+use futures::StreamExt;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 // Re-export key types
 pub use ankql::ast::{OrderByItem as OrderBy, Predicate as Filter};
 pub use ankurah_proto::EntityId as Id;
 pub use ankurah_signals;
+pub use builder::ScrollManagerBuilder;
+pub use config::{RecoveryStrategy, ScrollConfig};
+pub use diff::{VisibleSetChange, VisibleSetDiff};
+pub use error::ScrollError;
+pub use metrics::{ScrollMetrics, SlideMetrics};
+pub use stream::VisibleSetStream;
 
 // ============================================================================
 // Core Types
@@ -34,8 +53,24 @@ pub struct VisibleSet<V> {
     pub has_more_following: bool,
     /// True if renderer should auto-scroll to end when items change
     pub should_auto_scroll: bool,
-    /// Error if intersection calculation failed (continuation item not found in result)
-    pub error: Option<String>,
+    /// Failure behind this emission (unrecovered intersection, rejected query, ...)
+    pub error: Option<ScrollError>,
+    /// Intersection failure behind this emission and the recovery that was applied
+    pub intersection_failure: Option<IntersectionFailure>,
+    /// Items in the window whose content changed in place since the previous emission
+    pub updated: Vec<EntityId>,
+    /// Items of the previous emission whose ORDER BY values changed, so they moved within
+    /// the window or out of it
+    pub moved: Vec<EntityId>,
+    /// A slide toward earlier items is in flight (show a spinner at the leading edge)
+    pub loading_preceding: bool,
+    /// A slide toward later items (or a reload of the live window) is in flight
+    pub loading_following: bool,
+    /// The initial window hasn't loaded yet (`start()` hasn't completed)
+    pub initial_loading: bool,
+    /// Position in the manager's emissions (0 before the first), so a consumer handed the
+    /// same emission twice can tell
+    pub(crate) emission: u64,
 }
 
 impl<V> Default for VisibleSet<V> {
@@ -47,6 +82,13 @@ impl<V> Default for VisibleSet<V> {
             has_more_following: false,
             should_auto_scroll: true,
             error: None,
+            intersection_failure: None,
+            updated: Vec::new(),
+            moved: Vec::new(),
+            loading_preceding: false,
+            loading_following: false,
+            initial_loading: true,
+            emission: 0,
         }
     }
 }
@@ -59,6 +101,30 @@ pub struct Intersection {
     pub direction: LoadDirection,
 }
 
+/// A slide whose anchor item was missing from its result
+#[derive(Clone, Debug, PartialEq)]
+pub struct IntersectionFailure {
+    /// The anchor that was not found
+    pub anchor: EntityId,
+    /// Direction of the failed slide
+    pub direction: LoadDirection,
+    /// How the window was recovered (see `ScrollConfig::intersection_recovery`)
+    pub recovery: RecoveryAction,
+}
+
+/// Recovery applied after an intersection failure
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecoveryAction {
+    /// Not recovered: the result was emitted without an intersection (`error` is set)
+    None,
+    /// The slide was re-issued with a wider window and found the anchor
+    RetriedWider,
+    /// The window was anchored on this item, the nearest remaining one by sort key
+    NearestNeighbor(EntityId),
+    /// The live window was reloaded
+    JumpedToLive,
+}
+
 /// Direction for loading more items, relative to display_order.
 ///
 /// The display_order is set on the ScrollManager constructor and can be any valid
@@ -88,6 +154,50 @@ struct PendingSlide {
     direction: LoadDirection,
     /// Whether ORDER BY is reversed (for forward slides)
     reversed_order: bool,
+    /// Livequery selection version issued for this slide; results are only attributed to
+    /// the slide while it is still the current selection
+    version: u32,
+    /// Anchor's value of the first ORDER BY field when the slide was issued
+    anchor_key: Option<Value>,
+    /// Reload of the live window rather than a cursor slide
+    to_live: bool,
+    /// The cursor is the newest item, so the result already holds the live window
+    reaches_live_edge: bool,
+    /// Earlier failure this slide is recovering from (reported when it lands)
+    recovering: Option<IntersectionFailure>,
+    /// Items deleted while the slide was in flight, counted toward the limit+1 sentinel
+    /// when detecting end of data
+    deleted: usize,
+    /// Items around the anchor (nearest first, same viewport edge first) with their
+    /// ORDER BY values, to hold the position if the anchor itself is re-sorted
+    anchor_neighbors: Vec<(EntityId, SortKey)>,
+    /// Items of the window beyond the cursor that `max_window_size` left out of the query
+    evicted: usize,
+    /// When the slide's selection was issued (set by issue_slide, for slide latency)
+    issued_at: Option<web_time::Instant>,
+}
+
+/// ORDER BY values of an item, one per display_order field
+type SortKey = Vec<Option<Value>>;
+
+/// Shape of the query currently backing the window (set by the last applied slide,
+/// or by a mid-history start position)
+#[derive(Clone, Debug)]
+struct ActiveQuery {
+    /// Items kept from the result (the query fetches limit+1)
+    limit: usize,
+    /// Side of the window the limit+1 sentinel is trimmed from
+    direction: LoadDirection,
+    /// Whether ORDER BY is reversed relative to display_order
+    reversed_order: bool,
+    /// Items deleted since the query was issued (see `PendingSlide::deleted`)
+    deleted: usize,
+}
+
+impl From<&PendingSlide> for ActiveQuery {
+    fn from(slide: &PendingSlide) -> Self {
+        Self { limit: slide.limit, direction: slide.direction, reversed_order: slide.reversed_order, deleted: slide.deleted }
+    }
 }
 
 /// Current scroll mode
@@ -115,6 +225,10 @@ pub struct ScrollDebugInfo {
     pub update_count: u32,
     /// Whether a pagination update is currently pending
     pub update_pending: bool,
+    /// Extra items added to the trigger threshold and slide limit for the reported scroll velocity
+    pub prefetch_distance: usize,
+    /// Items evicted from the far side by `max_window_size` on the last applied slide
+    pub evicted_items: usize,
 }
 
 // ============================================================================
@@ -122,16 +236,21 @@ pub struct ScrollDebugInfo {
 // ============================================================================
 
 /// Convert an Ankurah Value to an AnkQL Literal for predicate construction
-fn value_to_literal(value: &Value) -> Literal {
+///
+/// Only scalar values have a meaningful order for cursor comparisons.
+fn value_to_literal(value: &Value) -> Result<Literal, ScrollError> {
     match value {
-        Value::I16(v) => Literal::I16(*v),
-        Value::I32(v) => Literal::I32(*v),
-        Value::I64(v) => Literal::I64(*v),
-        Value::F64(v) => Literal::F64(*v),
-        Value::Bool(v) => Literal::Bool(*v),
-        Value::String(v) => Literal::String(v.clone()),
-        // For other types, convert to string representation
-        _ => Literal::String(format!("{:?}", value)),
+        Value::I16(v) => Ok(Literal::I16(*v)),
+        Value::I32(v) => Ok(Literal::I32(*v)),
+        Value::I64(v) => Ok(Literal::I64(*v)),
+        Value::F64(v) => Ok(Literal::F64(*v)),
+        Value::Bool(v) => Ok(Literal::Bool(*v)),
+        Value::String(v) => Ok(Literal::String(v.clone())),
+        Value::EntityId(v) => Ok(Literal::EntityId(v.to_ulid())),
+        Value::Object(_) | Value::Binary(_) | Value::Json(_) => Err(ScrollError::CursorConversionFailed(format!(
+            "{:?} values cannot be used as a cursor",
+            ValueType::of(value)
+        ))),
     }
 }
 
@@ -141,25 +260,100 @@ fn value_to_literal(value: &Value) -> Literal {
 
 /// Virtual scroll manager with Ankurah LiveQuery integration
 pub struct ScrollManager<V: View + Clone + Send + Sync + 'static> {
+    state: Arc<ScrollState<V>>,
+    /// Livequery subscription, dropped by `close()`
+    subscription: Mutex<Option<ankurah_signals::SubscriptionGuard>>,
+}
+
+/// A scroll event whose evaluation was deferred while a slide was in flight
+#[derive(Clone, Copy, Debug)]
+struct ScrollIntent {
+    first_visible: EntityId,
+    last_visible: EntityId,
+    scrolling_backward: bool,
+    velocity: f64,
+}
+
+/// Window neighbours of an item that left the window, recorded so that scroll events and
+/// anchors still naming it can be resolved to the nearest remaining item
+#[derive(Clone, Copy, Debug)]
+struct Departed {
+    preceding: Option<EntityId>,
+    following: Option<EntityId>,
+    /// No longer matches the predicate (deleted), rather than moved out of the window
+    deleted: bool,
+    /// Order of departure, so the oldest entries are evicted first
+    sequence: u64,
+}
+
+/// Outcome of recovering from an intersection failure
+enum Recovered {
+    /// A recovery query was issued; its result replaces the failed one
+    Reissued,
+    /// The failed result is usable with this intersection
+    Anchored(Intersection, IntersectionFailure),
+    /// The failed result is emitted without an intersection
+    Unanchored(IntersectionFailure),
+    /// The failed result is trimmed to the live window
+    Live(IntersectionFailure),
+}
+
+/// State shared with the livequery subscription, which holds it weakly so that query
+/// results can re-evaluate scroll intent queued while a slide was pending
+struct ScrollState<V: View + Clone + Send + Sync + 'static> {
     livequery: LiveQuery<V>,
-    predicate: Predicate,
+    /// Base filter (replaced by `set_predicate`)
+    predicate: Mutex<Predicate>,
     display_order: Vec<OrderByItem>,
     visible_set: Mut<VisibleSet<V>>,
+    /// Incremental changes behind the most recent visible_set emission
+    visible_set_diff: Mut<VisibleSetDiff<V>>,
     mode: Mut<ScrollMode>,
     /// Whether start() has been called and initial state set
     initialized: Mut<bool>,
+    /// Whether close() has been called; no further results or scroll events are processed
+    closed: Mut<bool>,
+    /// Fired by close() to cancel a start() still waiting for the initial result
+    close_tx: Mutex<Option<oneshot::Sender<()>>>,
+    close_rx: Shared<oneshot::Receiver<()>>,
     /// Pending slide operation (set before query, consumed in callback)
     pending: Mut<Option<PendingSlide>>,
+    /// Most recently applied slide - the shape (order, limit) of the current query
+    active_slide: Mut<Option<ActiveQuery>>,
+    /// Latest scroll event received while `pending` was set (replayed once the slide lands)
+    queued_scroll: Mut<Option<ScrollIntent>>,
+    /// Items that recently left the window, keyed by id
+    departed: Mutex<HashMap<EntityId, Departed>>,
+    /// ORDER BY values of the emitted window's items, to detect re-sorted items
+    sort_keys: Mutex<HashMap<EntityId, SortKey>>,
+    /// Re-sorted items dropped from the window that the livequery still returns
+    displaced: Mutex<HashSet<EntityId>>,
     /// Oldest visible item when last trigger fired (for debouncing based on user scroll distance)
     last_trigger_oldest_visible: Mut<Option<EntityId>>,
     /// Debug info about current scroll position and buffer state
     debug_info: Mut<ScrollDebugInfo>,
     /// Counter for pagination updates initiated
     update_count: std::sync::atomic::AtomicU32,
+    /// Counter for departures recorded (orders `departed` entries for eviction)
+    departure_count: std::sync::atomic::AtomicU64,
+    /// Counter for visible_set emissions (stamped as `VisibleSet::emission`)
+    emission_count: std::sync::atomic::AtomicU64,
     minimum_row_height: u32,
-    buffer_factor: f64,
-    viewport_height: u32,
-    _subscription: ankurah_signals::SubscriptionGuard,
+    config: ScrollConfig,
+    /// Viewport height in pixels (changed by `set_viewport_height`)
+    viewport_height: std::sync::atomic::AtomicU32,
+    /// Initial position when not starting at the live edge (value of the first ORDER BY field)
+    start_at: Option<Value>,
+    /// Whether display_order is DESC (results need reversal for oldest-first display)
+    is_desc: bool,
+    metrics: Option<Arc<dyn ScrollMetrics>>,
+    /// Membership of the livequery result as of start() or the last notification
+    notified_ids: Mutex<HashSet<EntityId>>,
+    /// Held while a result is applied, so a notification and an unnotified result can't
+    /// both land the same slide
+    landing: Mutex<()>,
+    /// This state, for tasks spawned from sync entry points
+    this: std::sync::Weak<ScrollState<V>>,
 }
 
 impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
@@ -179,34 +373,117 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
         minimum_row_height: u32,
         buffer_factor: f64,
         viewport_height: u32,
+    ) -> Result<Self, ankurah::error::RetrievalError> {
+        let config = ScrollConfig::default().buffer_factor(buffer_factor);
+        Self::with_config(ctx, predicate, display_order, minimum_row_height, viewport_height, config)
+    }
+
+    /// Create a new scroll manager with custom pagination thresholds
+    ///
+    /// # Arguments
+    /// * `ctx` - Ankurah context
+    /// * `predicate` - Filter predicate (e.g., `"room_id = 'abc'"`)
+    /// * `display_order` - Visual order (e.g., `"timestamp DESC"` for chat)
+    /// * `minimum_row_height` - Guaranteed minimum item height in pixels
+    /// * `viewport_height` - Viewport height in pixels
+    /// * `config` - Trigger, buffer, debounce and window size settings (validated here)
+    pub fn with_config(
+        ctx: &Context,
+        predicate: impl TryInto<Predicate, Error = impl std::fmt::Debug>,
+        display_order: impl IntoOrderBy,
+        minimum_row_height: u32,
+        viewport_height: u32,
+        config: ScrollConfig,
     ) -> Result<Self, ankurah::error::RetrievalError> {
         let predicate = predicate.try_into().expect("Failed to parse predicate");
         let display_order = display_order
             .into_order_by()
             .expect("Failed to parse order");
-        let buffer_factor = buffer_factor.max(2.0);
+        Self::from_parts(ctx, predicate, display_order, minimum_row_height, viewport_height, config, None, None)
+    }
 
+    /// Start building a scroll manager with named options
+    ///
+    /// ```ignore
+    /// let sm = ScrollManager::<MessageView>::builder(&ctx)
+    ///     .predicate("room = 'general'")
+    ///     .order_by("timestamp DESC")
+    ///     .viewport(600)
+    ///     .row_height(40)
+    ///     .build()?;
+    /// ```
+    pub fn builder(ctx: &Context) -> ScrollManagerBuilder<V> {
+        ScrollManagerBuilder::new(ctx)
+    }
+
+    /// Shared constructor: `start_at` positions the initial window at (and before) the
+    /// given value of the first ORDER BY field instead of at the live edge
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn from_parts(
+        ctx: &Context,
+        predicate: Predicate,
+        display_order: Vec<OrderByItem>,
+        minimum_row_height: u32,
+        viewport_height: u32,
+        config: ScrollConfig,
+        start_at: Option<Value>,
+        metrics: Option<Arc<dyn ScrollMetrics>>,
+    ) -> Result<Self, ankurah::error::RetrievalError> {
         // Compute initial limit
         let screen_items = windowing::screen_items(viewport_height, minimum_row_height);
-        let threshold = buffer_factor / 2.0;
-        let limit = windowing::live_window_size(screen_items, threshold);
+        config
+            .validate(screen_items)
+            .map_err(|e| ankurah::error::RetrievalError::Other(format!("Invalid scroll config: {}", e)))?;
+        let limit = windowing::live_window_size(screen_items, config.threshold_screens());
+
+        // Starting mid-history is an initial backward slide: items up to and including
+        // start_at, fetching limit+1 to detect older items
+        let start_literal = start_at
+            .as_ref()
+            .map(value_to_literal)
+            .transpose()
+            .map_err(|e| ankurah::error::RetrievalError::Other(e.to_string()))?;
+        let start_query = match (start_literal, display_order.first()) {
+            (Some(literal), Some(order_item)) => Some((
+                Predicate::And(
+                    Box::new(predicate.clone()),
+                    Box::new(Predicate::Comparison {
+                        left: Box::new(Expr::Path(PathExpr::simple(order_item.path.first()))),
+                        operator: ComparisonOperator::LessThanOrEqual,
+                        right: Box::new(Expr::Literal(literal)),
+                    }),
+                ),
+                ActiveQuery { limit, direction: LoadDirection::Backward, reversed_order: false, deleted: 0 },
+            )),
+            _ => None,
+        };
 
         // Create livequery with initial selection
-        let selection = Selection {
-            predicate: predicate.clone(),
-            order_by: Some(display_order.clone()),
-            limit: Some(limit as u64),
+        let selection = match &start_query {
+            Some((start_predicate, active)) => Selection {
+                predicate: start_predicate.clone(),
+                order_by: Some(display_order.clone()),
+                limit: Some((active.limit + 1) as u64),
+            },
+            None => Selection {
+                predicate: predicate.clone(),
+                order_by: Some(display_order.clone()),
+                limit: Some(limit as u64),
+            },
         };
         let livequery: LiveQuery<V> = ctx.query(selection)?;
 
         // Create signals
         let visible_set: Mut<VisibleSet<V>> = Mut::new(VisibleSet::default());
+        let visible_set_diff: Mut<VisibleSetDiff<V>> = Mut::new(VisibleSetDiff::default());
         let pending: Mut<Option<PendingSlide>> = Mut::new(None);
+        let active_slide: Mut<Option<ActiveQuery>> = Mut::new(start_query.map(|(_, active)| active));
         let last_trigger_oldest_visible: Mut<Option<EntityId>> = Mut::new(None);
-        let mode: Mut<ScrollMode> = Mut::new(ScrollMode::Live);
+        let mode: Mut<ScrollMode> = Mut::new(if start_at.is_some() { ScrollMode::Backward } else { ScrollMode::Live });
         let initialized: Mut<bool> = Mut::new(false);
+        let (close_tx, close_rx) = oneshot::channel();
         let debug_info: Mut<ScrollDebugInfo> = Mut::new(ScrollDebugInfo {
-            trigger_threshold: screen_items,
+            trigger_threshold: config.trigger_items(screen_items),
             ..Default::default()
         });
 
@@ -216,245 +493,954 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
             .map(|o| o.direction == OrderDirection::Desc)
             .unwrap_or(false);
 
+        let state = Arc::new_cyclic(|this| ScrollState {
+            livequery,
+            predicate: Mutex::new(predicate),
+            display_order,
+            visible_set,
+            visible_set_diff,
+            mode,
+            initialized,
+            closed: Mut::new(false),
+            close_tx: Mutex::new(Some(close_tx)),
+            close_rx: close_rx.shared(),
+            pending,
+            active_slide,
+            queued_scroll: Mut::new(None),
+            departed: Mutex::new(HashMap::new()),
+            sort_keys: Mutex::new(HashMap::new()),
+            displaced: Mutex::new(HashSet::new()),
+            last_trigger_oldest_visible,
+            debug_info,
+            update_count: std::sync::atomic::AtomicU32::new(0),
+            departure_count: std::sync::atomic::AtomicU64::new(0),
+            emission_count: std::sync::atomic::AtomicU64::new(0),
+            minimum_row_height,
+            config,
+            viewport_height: std::sync::atomic::AtomicU32::new(viewport_height),
+            start_at,
+            is_desc,
+            metrics,
+            notified_ids: Mutex::new(HashSet::new()),
+            landing: Mutex::new(()),
+            this: this.clone(),
+        });
+
         // Subscribe to livequery changes (for updates after initialization)
-        let visible_set_clone = visible_set.clone();
-        let pending_clone = pending.clone();
-        let mode_clone = mode.clone();
-        let initialized_clone = initialized.clone();
-        let subscription = livequery.subscribe(move |changeset: ChangeSet<V>| {
-            tracing::trace!("[subscription] CALLBACK FIRED");
-
-            // Skip if not yet initialized (start() will handle initial set)
-            if !initialized_clone.peek() {
-                tracing::debug!("[subscription] skipping - not yet initialized");
-                return;
+        let weak_state = Arc::downgrade(&state);
+        let subscription = state.livequery.subscribe(move |changeset: ChangeSet<V>| {
+            if let Some(state) = weak_state.upgrade() {
+                let _landing = state.landing.lock().unwrap();
+                if state.already_applied(&changeset) {
+                    tracing::debug!("[subscription] skipping - already applied");
+                    return;
+                }
+                state.apply_changeset(changeset);
             }
+        });
 
-            let current = visible_set_clone.peek();
-            let mut items: Vec<V> = changeset.resultset.peek();
-            tracing::trace!("[subscription] processing {} items, current has {}", items.len(), current.items.len());
-
-            // Consume pending slide state - but only when the query is fully loaded
-            // This prevents intermediate callbacks (from incremental delta application) from
-            // incorrectly consuming the slide before the full result is ready.
-            // The is_loaded() check handles both cases:
-            // - Normal case: enough items returned, query is loaded
-            // - Edge case: fewer items than limit (at data boundary), but query is still loaded
-            let pending_slide = pending_clone.peek();
-            let should_process_slide = pending_slide.is_some() && changeset.resultset.is_loaded();
-            let slide = if should_process_slide {
-                pending_clone.set(None);
-                pending_slide
-            } else {
-                None
-            };
+        Ok(Self {
+            state,
+            subscription: Mutex::new(Some(subscription)),
+        })
+    }
 
-            // Normally, DESC order needs reversal to get oldest-first display order
-            // But if we used reversed order (ASC for forward), items are already oldest-first
-            let used_reversed_order = slide.as_ref().map(|s| s.reversed_order).unwrap_or(false);
-            if is_desc && !used_reversed_order {
-                items.reverse();
+    /// Initialize the scroll manager (waits for initial query results)
+    /// generally this should be backgrounded and not awaited on.
+    ///
+    /// Returns early, leaving the visible set empty, if the manager is closed meanwhile.
+    pub async fn start(&self) {
+        if let Err(e) = self.state.start().await {
+            tracing::debug!("[start] {}", e);
+        }
+    }
+
+    /// Initialize the scroll manager, giving up if the initial result takes longer than
+    /// `timeout`
+    ///
+    /// On `ScrollError::Timeout` the manager is still usable and start may be retried.
+    /// Returns `ScrollError::Closed` if the manager is closed before the result arrives.
+    pub async fn start_with_timeout(&self, timeout: Duration) -> Result<(), ScrollError> {
+        let start = std::pin::pin!(self.state.start());
+        match futures::future::select(start, futures_timer::Delay::new(timeout)).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(ScrollError::Timeout(timeout)),
+        }
+    }
+
+    /// Shut the manager down: unsubscribe from the livequery, drop any in-flight slide
+    /// and queued scroll event, and cancel a pending start()
+    ///
+    /// The last visible set stays readable; later scroll events are ignored. Dropping the
+    /// manager has the same effect, so this is only needed when the manager is shared
+    /// (e.g. held by a JS or Kotlin wrapper) and must release its resources now.
+    pub fn close(&self) {
+        self.state.close();
+        self.subscription.lock().unwrap().take();
+    }
+
+    /// Whether `close()` has been called
+    pub fn is_closed(&self) -> bool {
+        self.state.closed.peek()
+    }
+
+    // Accessors
+    pub fn visible_set(&self) -> Read<VisibleSet<V>> {
+        self.state.visible_set.read()
+    }
+
+    /// The visible set and every later emission, as a `futures::Stream`
+    ///
+    /// For consumers without a signal-aware renderer; see `VisibleSetStream`.
+    pub fn visible_set_stream(&self) -> VisibleSetStream<V> {
+        let (tx, rx) = mpsc::unbounded();
+        // Subscribe before reading the current value, so an emission in between is
+        // repeated rather than lost
+        let subscription = self.state.visible_set.read().subscribe(move |vs: VisibleSet<V>| {
+            let _ = tx.unbounded_send(vs);
+        });
+        VisibleSetStream::new(self.state.visible_set.peek(), rx, subscription, self.state.close_rx.clone())
+    }
+
+    /// Wait until the window has settled, and return it
+    ///
+    /// Settled means the initial window has loaded and no slide is in flight or waiting to
+    /// be replayed from a scroll event. Resolves straight away if that's already the case
+    /// (e.g. after an `on_scroll` that didn't trigger a slide), and with the last visible
+    /// set if the manager is closed meanwhile.
+    pub async fn next_settled(&self) -> VisibleSet<V> {
+        // Re-check whenever the window, the pending slide or the queued scroll changes
+        let (tx, mut changed) = mpsc::unbounded();
+        let _subscriptions = [
+            self.state.visible_set.read().subscribe({
+                let tx = tx.clone();
+                move |_: VisibleSet<V>| {
+                    let _ = tx.unbounded_send(());
+                }
+            }),
+            self.state.pending.read().subscribe({
+                let tx = tx.clone();
+                move |_: Option<PendingSlide>| {
+                    let _ = tx.unbounded_send(());
+                }
+            }),
+            self.state.queued_scroll.read().subscribe(move |_: Option<ScrollIntent>| {
+                let _ = tx.unbounded_send(());
+            }),
+        ];
+
+        loop {
+            let current = self.state.visible_set.peek();
+            if self.state.closed.peek() || self.state.is_settled(&current) {
+                return current;
+            }
+            if let Either::Right(_) = futures::future::select(changed.next(), self.state.close_rx.clone()).await {
+                return self.state.visible_set.peek();
             }
+        }
+    }
 
-            // Process result based on pending slide direction
-            let (has_more_preceding, has_more_following, intersection, error) = if let Some(ref slide) = slide {
-                // Detect end of data: we requested limit+1, so len > limit means more exist
-                let (has_more_preceding, has_more_following) = match slide.direction {
-                    LoadDirection::Backward => {
-                        let more_older = if items.len() > slide.limit {
-                            items.remove(0); // Remove extra oldest item
-                            true
-                        } else {
-                            false
-                        };
-                        (more_older, true) // Backward slide means we left live edge
+    /// Incremental changes behind each visible_set emission
+    ///
+    /// Only updated when the window's items change (not for flag-only emissions such as
+    /// mode changes), so patch-based renderers should read flags from `visible_set()`.
+    pub fn visible_set_diff(&self) -> Read<VisibleSetDiff<V>> {
+        self.state.visible_set_diff.read()
+    }
+
+    pub fn mode(&self) -> ScrollMode {
+        self.state.mode.peek()
+    }
+
+    /// Get the current selection (predicate + order by) as a string.
+    pub fn current_selection(&self) -> String {
+        let (selection, _version) = self.state.livequery.selection().peek();
+        format!("{}", selection)
+    }
+
+    /// Get debug info about scroll position and buffer state
+    pub fn debug_info(&self) -> Read<ScrollDebugInfo> {
+        self.state.debug_info.read()
+    }
+
+    /// Notify the scroll manager of visible item changes
+    ///
+    /// # Arguments
+    /// * `first_visible` - EntityId of the first (oldest) visible item
+    /// * `last_visible` - EntityId of the last (newest) visible item
+    /// * `scrolling_backward` - True if user is scrolling toward older items
+    pub fn on_scroll(&self, first_visible: EntityId, last_visible: EntityId, scrolling_backward: bool) {
+        self.on_scroll_with_velocity(first_visible, last_visible, scrolling_backward, 0.0);
+    }
+
+    /// Notify the scroll manager of visible item changes, with the current scroll speed
+    ///
+    /// Fast scrolls trigger earlier and fetch further ahead, by the items that could pass
+    /// in `ScrollConfig::prefetch_seconds` at this speed (capped at one buffer).
+    ///
+    /// # Arguments
+    /// * `first_visible` - EntityId of the first (oldest) visible item
+    /// * `last_visible` - EntityId of the last (newest) visible item
+    /// * `scrolling_backward` - True if user is scrolling toward older items
+    /// * `velocity` - Scroll speed in pixels per second (sign is ignored)
+    pub fn on_scroll_with_velocity(
+        &self,
+        first_visible: EntityId,
+        last_visible: EntityId,
+        scrolling_backward: bool,
+        velocity: f64,
+    ) {
+        self.state.on_scroll(ScrollIntent { first_visible, last_visible, scrolling_backward, velocity });
+    }
+
+    /// Return to the live edge (e.g. from a "Jump to latest" button)
+    ///
+    /// Replaces the window with the newest live-window items, superseding any slide in
+    /// flight, and resolves once the live window has been emitted (in Live mode). Does
+    /// nothing in Live mode.
+    pub async fn jump_to_live(&self) -> Result<(), ScrollError> {
+        self.state.jump_to_live().await
+    }
+
+    /// Resize the viewport (e.g. on rotation or window resize)
+    ///
+    /// Later slides size their buffers for the new height. In Live mode the live window
+    /// is reloaded at its new size; mid-history the current window is kept.
+    pub async fn set_viewport_height(&self, viewport_height: u32) -> Result<(), ScrollError> {
+        self.state.set_viewport_height(viewport_height).await
+    }
+
+    /// Replace the base filter (e.g. switching rooms) and reload the live window
+    ///
+    /// The ORDER BY is unchanged. Like `jump_to_live`, supersedes any slide in flight and
+    /// resolves once the new window has been emitted.
+    pub async fn set_predicate(
+        &self,
+        predicate: impl TryInto<Predicate, Error = impl std::fmt::Debug>,
+    ) -> Result<(), ScrollError> {
+        let predicate = predicate
+            .try_into()
+            .map_err(|e| ScrollError::QueryFailed(format!("invalid predicate: {:?}", e)))?;
+        self.state.set_predicate(predicate).await
+    }
+}
+
+impl<V: View + Clone + Send + Sync + 'static> ScrollState<V> {
+    /// Apply a livequery result to the visible set
+    fn apply_changeset(&self, changeset: ChangeSet<V>) {
+        tracing::trace!("[subscription] CALLBACK FIRED");
+        *self.notified_ids.lock().unwrap() = self.livequery.ids().into_iter().collect();
+
+        // A result delivered while close() was running
+        if self.closed.peek() {
+            tracing::debug!("[subscription] skipping - closed");
+            return;
+        }
+
+        // Skip if not yet initialized (start() will handle initial set)
+        if !self.initialized.peek() {
+            tracing::debug!("[subscription] skipping - not yet initialized");
+            return;
+        }
+
+        let current = self.visible_set.peek();
+        let mut items: Vec<V> = changeset.resultset.peek();
+        tracing::trace!("[subscription] processing {} items, current has {}", items.len(), current.items.len());
+
+        // Consume pending slide state - but only when the query is fully loaded
+        // This prevents intermediate callbacks (from incremental delta application) from
+        // incorrectly consuming the slide before the full result is ready.
+        // The is_loaded() check handles both cases:
+        // - Normal case: enough items returned, query is loaded
+        // - Edge case: fewer items than limit (at data boundary), but query is still loaded
+        let (_, selection_version) = self.livequery.selection().peek();
+        let mut pending_slide = self.pending.peek();
+        if let Some(superseded) = pending_slide.as_ref().filter(|slide| slide.version < selection_version) {
+            // A newer selection replaced this slide's query, so its result will never be
+            // loaded; drop it rather than match a later result against its anchor
+            tracing::debug!(
+                "[subscription] discarding superseded slide (version {} < {})",
+                superseded.version, selection_version
+            );
+            self.pending.set(None);
+            self.set_update_pending(false, self.update_count.load(std::sync::atomic::Ordering::Relaxed));
+            pending_slide = None;
+        }
+        // A LIMIT result isn't refilled after a removal, so deletions are counted toward
+        // the sentinel of whichever query they shrank
+        let predicate = self.predicate();
+        let deleted = changeset
+            .changes
+            .iter()
+            .filter(|change| {
+                matches!(change, ItemChange::Remove { item, .. }
+                    if !evaluate_predicate(item.entity(), &predicate).unwrap_or(false))
+            })
+            .count();
+        if deleted > 0 {
+            if let Some(in_flight) = pending_slide.as_mut() {
+                in_flight.deleted += deleted;
+                self.pending.set(Some(in_flight.clone()));
+            } else if let Some(mut active) = self.active_slide.peek() {
+                active.deleted += deleted;
+                self.active_slide.set(Some(active));
+            }
+        }
+        let should_process_slide = pending_slide.is_some() && changeset.resultset.is_loaded();
+        let slide = if should_process_slide {
+            self.pending.set(None);
+            self.active_slide.set(pending_slide.as_ref().map(ActiveQuery::from));
+            self.set_update_pending(false, self.update_count.load(std::sync::atomic::Ordering::Relaxed));
+            let mut debug_info = self.debug_info.peek();
+            debug_info.evicted_items = pending_slide.as_ref().map_or(0, |slide| slide.evicted);
+            self.debug_info.set(debug_info);
+            if let (Some(metrics), Some(slide)) = (&self.metrics, pending_slide.as_ref()) {
+                metrics.slide_completed(&SlideMetrics {
+                    direction: slide.direction,
+                    latency: slide.issued_at.map_or(Duration::ZERO, |issued_at| issued_at.elapsed()),
+                    items_fetched: items.len(),
+                    to_live: slide.to_live,
+                    recovery: slide.recovering.is_some(),
+                });
+            }
+            pending_slide
+        } else {
+            None
+        };
+        // Without a slide to consume (live updates, edits), results still come from
+        // the query issued by the last applied slide
+        let active = slide.as_ref().map(ActiveQuery::from).or_else(|| self.active_slide.peek());
+
+        // Normally, DESC order needs reversal to get oldest-first display order
+        // But if we used reversed order (ASC for forward), items are already oldest-first
+        let used_reversed_order = active.as_ref().map(|s| s.reversed_order).unwrap_or(false);
+        if self.is_desc && !used_reversed_order {
+            items.reverse();
+        }
+
+        // The livequery leaves re-sorted items at their old position (and in the result
+        // even once they sort past its LIMIT), so put them back in order ourselves
+        let moved = self.moved_items(&current.items, &items);
+        let limit_at_front = active.as_ref().is_none_or(|active| active.direction == LoadDirection::Backward);
+        let more_past_limit = if limit_at_front { current.has_more_preceding } else { current.has_more_following };
+        let dropped = self.reorder(&mut items, &moved, limit_at_front, more_past_limit);
+
+        // Process result based on pending slide direction
+        let (has_more_preceding, has_more_following, intersection, error, intersection_failure) = if let Some(slide) = slide.as_ref().filter(|slide| slide.to_live) {
+            // Live window reload: the sentinel is the oldest item and nothing is newer
+            let more_older = items.len() + dropped > slide.limit;
+            if items.len() > slide.limit {
+                items.remove(0);
+            }
+            self.set_mode(ScrollMode::Live);
+            self.last_trigger_oldest_visible.set(None);
+            (more_older, false, None, None, slide.recovering.clone())
+        } else if let Some(ref slide) = slide {
+            // Detect end of data: we requested limit+1, so len > limit means more exist
+            let (mut has_more_preceding, mut has_more_following) = match slide.direction {
+                LoadDirection::Backward => {
+                    let more_older = items.len() + slide.deleted + dropped > slide.limit;
+                    if items.len() > slide.limit {
+                        items.remove(0); // Remove extra oldest item
                     }
-                    LoadDirection::Forward => {
-                        let more_newer = if items.len() > slide.limit {
+                    (more_older, true) // Backward slide means we left live edge
+                }
+                LoadDirection::Forward => {
+                    let more_newer = if items.len() + slide.deleted + dropped > slide.limit {
+                        if items.len() > slide.limit {
                             items.pop(); // Remove extra newest item
-                            true
-                        } else {
-                            // Reached live edge - transition back to Live mode
-                            mode_clone.set(ScrollMode::Live);
-                            false
-                        };
-                        // Detect if we left items behind
-                        let more_older = current.has_more_preceding ||
-                            current.items.first().map(|old| items.first().map(|new|
-                                old.entity().id() != new.entity().id()
-                            ).unwrap_or(false)).unwrap_or(false);
-                        (more_older, more_newer)
-                    }
-                };
+                        }
+                        true
+                    } else {
+                        // Reached live edge - transition back to Live mode
+                        self.set_mode(ScrollMode::Live);
+                        false
+                    };
+                    // Detect if we left items behind
+                    let more_older = current.has_more_preceding ||
+                        current.items.first().map(|old| items.first().map(|new|
+                            old.entity().id() != new.entity().id()
+                        ).unwrap_or(false)).unwrap_or(false);
+                    (more_older, more_newer)
+                }
+            };
 
-                // Find anchor item for scroll stability (visible edge item, not cursor)
-                tracing::trace!(
-                    "[subscription] Looking for anchor {:?} in {} items",
-                    slide.anchor, items.len()
-                );
-                let (intersection, error) = match items.iter().position(|item| item.entity().id() == slide.anchor) {
-                    Some(index) => {
-                        let anchor_ts = items.get(index).and_then(|i| i.entity().value("timestamp"));
-                        tracing::trace!(
-                            "[subscription] INTERSECTION: anchor {:?} (ts={:?}) found at index {}",
-                            slide.anchor, anchor_ts, index
+            // Find anchor item for scroll stability (visible edge item, not cursor)
+            tracing::trace!(
+                "[subscription] Looking for anchor {:?} in {} items",
+                slide.anchor, items.len()
+            );
+            // The anchor's deletion may not have been emitted yet; record departures
+            // against this result so a deleted anchor resolves to its neighbour
+            self.record_departures(&current.items, &items);
+            // A re-sorted anchor is found at its new position; hold the old one through
+            // a neighbour instead
+            let anchor_index = match self.resorted_anchor_neighbor(slide, &items) {
+                Some(index) => Some(index),
+                None => items.iter().position(|item| item.entity().id() == slide.anchor),
+            };
+            let (intersection, error, intersection_failure) = match anchor_index {
+                Some(index) => {
+                    let entity_id = items[index].entity().id();
+                    tracing::trace!(
+                        "[subscription] INTERSECTION: anchor {:?} (key={:?}) found at index {}",
+                        entity_id, self.sort_key(&items[index]), index
+                    );
+                    (
+                        Some(Intersection {
+                            entity_id,
+                            index,
+                            direction: slide.direction,
+                        }),
+                        None,
+                        slide.recovering.clone(),
+                    )
+                },
+                None if self.departed_deleted(slide.anchor) => {
+                    // A deleted anchor isn't a failure: hold the position on its nearest
+                    // remaining neighbour (the one left at the same viewport edge)
+                    let toward_following = slide.direction == LoadDirection::Forward;
+                    match self.resolve_departed(slide.anchor, &items, toward_following) {
+                        Some(index) => {
+                            let entity_id = items[index].entity().id();
+                            tracing::debug!(
+                                "[subscription] anchor {:?} deleted, anchoring on neighbour {:?} at index {}",
+                                slide.anchor, entity_id, index
+                            );
+                            (Some(Intersection { entity_id, index, direction: slide.direction }), None, slide.recovering.clone())
+                        }
+                        None => (None, None, slide.recovering.clone()),
+                    }
+                }
+                None => {
+                    if slide.direction == LoadDirection::Forward {
+                        tracing::trace!("[subscription] Forward slide: no overlap, jumping to live");
+                        (None, None, slide.recovering.clone())
+                    } else {
+                        tracing::warn!(
+                            "[subscription] INTERSECTION FAILED: anchor {:?} not found in {} items",
+                            slide.anchor, items.len()
                         );
-                        (
-                            Some(Intersection {
-                                entity_id: slide.anchor,
-                                index,
+                        match self.recover_intersection(slide, &items) {
+                            // The recovery query's result replaces this one
+                            Recovered::Reissued => return,
+                            Recovered::Anchored(intersection, failure) => (Some(intersection), None, Some(failure)),
+                            Recovered::Unanchored(failure) => (None, Some(ScrollError::IntersectionFailed {
+                                anchor: slide.anchor,
                                 direction: slide.direction,
-                            }),
-                            None
-                        )
-                    },
-                    None => {
-                        if slide.direction == LoadDirection::Forward {
-                            tracing::trace!("[subscription] Forward slide: no overlap, jumping to live");
-                            (None, None)
-                        } else {
-                            tracing::error!(
-                                "[subscription] INTERSECTION FAILED: anchor {:?} not found in {} items",
-                                slide.anchor, items.len()
-                            );
-                            (None, Some(format!(
-                                "Intersection failed: anchor {} not found in result",
-                                slide.anchor
-                            )))
+                            }), Some(failure)),
+                            Recovered::Live(failure) => {
+                                let excess = items.len().saturating_sub(self.live_window_size());
+                                items.drain(..excess);
+                                has_more_preceding = has_more_preceding || excess > 0;
+                                has_more_following = false;
+                                self.set_mode(ScrollMode::Live);
+                                self.last_trigger_oldest_visible.set(None);
+                                (None, None, Some(failure))
+                            }
                         }
                     }
-                };
-
-                (has_more_preceding, has_more_following, intersection, error)
-            } else {
-                (current.has_more_preceding, current.has_more_following, None, None)
+                }
             };
 
-            tracing::trace!(
-                "[subscription] visible_set: items={}, has_more_preceding={}, has_more_following={}",
-                items.len(), has_more_preceding, has_more_following
-            );
+            (has_more_preceding, has_more_following, intersection, error, intersection_failure)
+        } else {
+            // Drop the limit+1 sentinel the active query fetched for has_more detection
+            let (mut has_more_preceding, mut has_more_following) = (current.has_more_preceding, current.has_more_following);
+            if let Some(ref active) = active {
+                let more = items.len() + active.deleted + dropped > active.limit;
+                match active.direction {
+                    LoadDirection::Backward => {
+                        if items.len() > active.limit {
+                            items.remove(0);
+                        }
+                        has_more_preceding |= more;
+                    }
+                    LoadDirection::Forward => {
+                        if items.len() > active.limit {
+                            items.pop();
+                        }
+                        has_more_following |= more;
+                    }
+                }
+            }
+            (has_more_preceding, has_more_following, None, None, None)
+        };
 
-            visible_set_clone.set(VisibleSet {
-                items,
-                intersection,
-                has_more_preceding,
-                has_more_following,
-                should_auto_scroll: mode_clone.peek() == ScrollMode::Live,
-                error,
-            });
+        tracing::trace!(
+            "[subscription] visible_set: items={}, has_more_preceding={}, has_more_following={}",
+            items.len(), has_more_preceding, has_more_following
+        );
+
+        // Entities edited in place that are still in the window, so renderers can
+        // re-render just those rows
+        let updated: Vec<EntityId> = changeset.changes.iter().filter_map(|change| match change {
+            ItemChange::Update { item, .. } => Some(item.entity().id()),
+            _ => None,
+        }).filter(|id| items.iter().any(|item| item.entity().id() == *id)).collect();
+        let diff = VisibleSetDiff::between(&current.items, &items, &updated);
+        let (loading_preceding, loading_following) = self.loading_edges();
+        if let (Some(metrics), Some(failure)) = (&self.metrics, intersection_failure.as_ref()) {
+            metrics.intersection_failed(failure);
+        }
+        self.record_departures(&current.items, &items);
+        self.record_sort_keys(&items);
+
+        self.emit(VisibleSet {
+            items,
+            intersection,
+            has_more_preceding,
+            has_more_following,
+            should_auto_scroll: self.mode.peek() == ScrollMode::Live,
+            error,
+            intersection_failure,
+            updated,
+            moved,
+            loading_preceding,
+            loading_following,
+            initial_loading: false,
+            emission: 0,
         });
+        if !diff.is_empty() {
+            self.visible_set_diff.set(diff);
+        }
 
-        Ok(Self {
-            livequery,
-            predicate,
-            display_order,
-            visible_set,
-            mode,
-            initialized,
-            pending,
-            last_trigger_oldest_visible,
-            debug_info,
-            update_count: std::sync::atomic::AtomicU32::new(0),
-            minimum_row_height,
-            buffer_factor,
-            viewport_height,
-            _subscription: subscription,
-        })
+        // The slide has landed: replay the latest scroll received while it was in flight
+        // against the new window (it may trigger the next slide straight away)
+        if slide.is_some() {
+            if let Some(intent) = self.queued_scroll.peek() {
+                tracing::debug!("[subscription] re-evaluating queued scroll intent");
+                self.queued_scroll.set(None);
+                self.on_scroll(intent);
+            }
+        }
     }
 
-    /// Initialize the scroll manager (waits for initial query results)
-    /// generally this should be backgrounded and not awaited on.
-    pub async fn start(&self) {
-        self.livequery.wait_initialized().await;
+    async fn start(&self) -> Result<(), ScrollError> {
+        let initialized = std::pin::pin!(self.livequery.wait_initialized());
+        if let Either::Right(_) = futures::future::select(initialized, self.close_rx.clone()).await {
+            return Err(ScrollError::Closed);
+        }
+        if self.closed.peek() {
+            return Err(ScrollError::Closed);
+        }
 
         let mut items: Vec<V> = self.livequery.peek();
+        *self.notified_ids.lock().unwrap() = items.iter().map(|item| item.entity().id()).collect();
 
-        let is_desc = self
-            .display_order
-            .first()
-            .map(|o| o.direction == OrderDirection::Desc)
-            .unwrap_or(false);
-        if is_desc {
+        if self.is_desc {
             items.reverse();
         }
 
         let live_window = self.live_window_size();
-        let has_more_preceding = items.len() >= live_window;
+        let (has_more_preceding, has_more_following, intersection) = if self.start_at.is_some() {
+            // Mid-history start: drop the limit+1 sentinel and anchor the start item at the
+            // bottom of the viewport. Newer items are assumed to exist; the first forward
+            // slide returns to Live mode if they don't.
+            let more_older = items.len() > live_window;
+            if more_older {
+                items.remove(0);
+            }
+            let intersection = items.last().map(|item| Intersection {
+                entity_id: item.entity().id(),
+                index: items.len() - 1,
+                direction: LoadDirection::Backward,
+            });
+            (more_older, true, intersection)
+        } else {
+            (items.len() >= live_window, false, None)
+        };
 
         tracing::debug!(
-            "[start] initial visible_set: items={}, has_more_preceding={}",
-            items.len(), has_more_preceding
+            "[start] initial visible_set: items={}, has_more_preceding={}, has_more_following={}",
+            items.len(), has_more_preceding, has_more_following
         );
 
-        self.visible_set.set(VisibleSet {
+        let diff = VisibleSetDiff::between(&self.visible_set.peek().items, &items, &[]);
+        self.record_sort_keys(&items);
+
+        self.emit(VisibleSet {
             items,
-            intersection: None,
+            intersection,
             has_more_preceding,
-            has_more_following: false,
-            should_auto_scroll: true,
+            has_more_following,
+            should_auto_scroll: self.mode.peek() == ScrollMode::Live,
             error: None,
+            intersection_failure: None,
+            updated: Vec::new(),
+            moved: Vec::new(),
+            loading_preceding: false,
+            loading_following: false,
+            initial_loading: false,
+            emission: 0,
         });
+        if !diff.is_empty() {
+            self.visible_set_diff.set(diff);
+        }
 
         // Mark as initialized - subscription callbacks will now process updates
         self.initialized.set(true);
+        Ok(())
     }
 
-    // Computed properties
-    fn threshold(&self) -> f64 {
-        self.buffer_factor / 2.0
+    /// The initial window has loaded and no slide is in flight or queued
+    fn is_settled(&self, visible_set: &VisibleSet<V>) -> bool {
+        !visible_set.initial_loading && self.pending.peek().is_none() && self.queued_scroll.peek().is_none()
     }
 
-    fn screen_items(&self) -> usize {
-        windowing::screen_items(self.viewport_height, self.minimum_row_height)
+    /// Stop processing results and scroll events, and cancel a waiting start()
+    fn close(&self) {
+        if self.closed.peek() {
+            return;
+        }
+        tracing::debug!("[close] closing scroll manager");
+        self.closed.set(true);
+        if let Some(close_tx) = self.close_tx.lock().unwrap().take() {
+            let _ = close_tx.send(());
+        }
+        self.queued_scroll.set(None);
+        if self.pending.peek().is_some() {
+            self.pending.set(None);
+            self.set_update_pending(false, self.update_count.load(std::sync::atomic::Ordering::Relaxed));
+        }
     }
 
-    fn live_window_size(&self) -> usize {
-        windowing::live_window_size(self.screen_items(), self.threshold())
+    /// Check that the manager can take a host command (started, not closed)
+    fn check_ready(&self, operation: &str) -> Result<(), ScrollError> {
+        if self.closed.peek() {
+            return Err(ScrollError::Closed);
+        }
+        if !self.initialized.peek() {
+            return Err(ScrollError::InvalidState(format!("{} called before start()", operation)));
+        }
+        Ok(())
     }
 
-    // Accessors
-    pub fn visible_set(&self) -> Read<VisibleSet<V>> {
-        self.visible_set.read()
+    /// Reload the live window unless already showing it
+    async fn jump_to_live(&self) -> Result<(), ScrollError> {
+        self.check_ready("jump_to_live")?;
+        if self.mode.peek() == ScrollMode::Live && self.pending.peek().is_none() {
+            return Ok(());
+        }
+        tracing::debug!("[jump_to_live] reloading the live window");
+        self.reload().await
     }
 
-    pub fn mode(&self) -> ScrollMode {
-        self.mode.peek()
+    /// Resize the viewport, reloading the live window at its new size in Live mode
+    async fn set_viewport_height(&self, viewport_height: u32) -> Result<(), ScrollError> {
+        self.check_ready("set_viewport_height")?;
+        let screen_items = windowing::screen_items(viewport_height, self.minimum_row_height);
+        self.config.validate(screen_items).map_err(ScrollError::InvalidState)?;
+
+        let previous_window = self.live_window_size();
+        self.viewport_height.store(viewport_height, std::sync::atomic::Ordering::Relaxed);
+        let mut debug_info = self.debug_info.peek();
+        debug_info.trigger_threshold = self.config.trigger_items(screen_items);
+        self.debug_info.set(debug_info);
+
+        // Mid-history the next slide picks up the new size; a live window has to be
+        // refetched, as its size is the query limit
+        if self.mode.peek() != ScrollMode::Live || self.pending.peek().is_some() || self.live_window_size() == previous_window {
+            return Ok(());
+        }
+        tracing::debug!("[set_viewport_height] live window {} -> {}", previous_window, self.live_window_size());
+        self.reload().await
     }
 
-    /// Get the current selection (predicate + order by) as a string.
-    pub fn current_selection(&self) -> String {
-        let (selection, _version) = self.livequery.selection().peek();
-        format!("{}", selection)
+    /// Replace the base filter and reload the live window
+    async fn set_predicate(&self, predicate: Predicate) -> Result<(), ScrollError> {
+        self.check_ready("set_predicate")?;
+        tracing::debug!("[set_predicate] {}", predicate);
+        *self.predicate.lock().unwrap() = predicate;
+        // Departures and re-sorts of the old filter's items no longer apply
+        self.departed.lock().unwrap().clear();
+        self.displaced.lock().unwrap().clear();
+        self.reload().await
     }
 
-    /// Get debug info about scroll position and buffer state
-    pub fn debug_info(&self) -> Read<ScrollDebugInfo> {
-        self.debug_info.read()
+    /// Supersede any slide in flight with a live window reload, and wait for it to land
+    async fn reload(&self) -> Result<(), ScrollError> {
+        self.queued_scroll.set(None);
+        self.last_trigger_oldest_visible.set(None);
+        if !self.reload_live(None) {
+            return Err(self.visible_set.peek().error.unwrap_or_else(|| {
+                ScrollError::QueryFailed("update_selection failed".to_string())
+            }));
+        }
+        let Some(version) = self.pending.peek().map(|slide| slide.version) else {
+            return Ok(());
+        };
+
+        // The subscription callback (or watch_unnotified, when membership didn't change)
+        // lands the result; wait until that slide is no longer pending
+        let (tx, mut changed) = mpsc::unbounded();
+        let _subscription = self.pending.read().subscribe(move |_: Option<PendingSlide>| {
+            let _ = tx.unbounded_send(());
+        });
+        loop {
+            if self.closed.peek() {
+                return Err(ScrollError::Closed);
+            }
+            if self.pending.peek().is_none_or(|slide| slide.version != version) {
+                // Pending is cleared before the window is emitted; both landing paths hold
+                // the lock until it is
+                drop(self.landing.lock().unwrap());
+                return Ok(());
+            }
+            if let Either::Right(_) = futures::future::select(changed.next(), self.close_rx.clone()).await {
+                return Err(ScrollError::Closed);
+            }
+        }
     }
 
-    /// Notify the scroll manager of visible item changes
+    /// Remember the neighbours of items in `old` that are missing from `new`
+    fn record_departures(&self, old: &[V], new: &[V]) {
+        let present: HashSet<EntityId> = new.iter().map(|item| item.entity().id()).collect();
+        let predicate = self.predicate();
+        let mut departed = self.departed.lock().unwrap();
+        departed.retain(|id, _| !present.contains(id));
+        for (index, item) in old.iter().enumerate() {
+            let id = item.entity().id();
+            if present.contains(&id) {
+                continue;
+            }
+            // Recording the same departure again (a slide's result, then its emission)
+            // keeps its place in the eviction order
+            let sequence = departed.get(&id).map_or_else(
+                || self.departure_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+                |departed| departed.sequence,
+            );
+            departed.insert(id, Departed {
+                preceding: index.checked_sub(1).map(|i| old[i].entity().id()),
+                following: old.get(index + 1).map(|item| item.entity().id()),
+                deleted: !evaluate_predicate(item.entity(), &predicate).unwrap_or(false),
+                sequence,
+            });
+        }
+        // Ids older than a few windows are no longer reported by renderers: keep the
+        // most recent departures
+        let capacity = 4 * self.live_window_size();
+        if departed.len() > capacity {
+            let mut sequences: Vec<u64> = departed.values().map(|departed| departed.sequence).collect();
+            sequences.sort_unstable();
+            let oldest_kept = sequences[sequences.len() - capacity];
+            departed.retain(|_, departed| departed.sequence >= oldest_kept);
+        }
+    }
+
+    /// ORDER BY values of `item`, one per display_order field
+    fn sort_key(&self, item: &V) -> SortKey {
+        self.display_order.iter().map(|order_item| item.entity().value(order_item.path.first())).collect()
+    }
+
+    /// Publish `visible_set` as the next emission
+    fn emit(&self, mut visible_set: VisibleSet<V>) {
+        visible_set.emission = self.emission_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+        self.visible_set.set(visible_set);
+    }
+
+    /// Remember the ORDER BY values of the emitted window
+    fn record_sort_keys(&self, items: &[V]) {
+        *self.sort_keys.lock().unwrap() = items.iter().map(|item| (item.entity().id(), self.sort_key(item))).collect();
+    }
+
+    /// Items of the emitted window (`old`) or of the new result whose ORDER BY values
+    /// changed since the window was emitted
+    fn moved_items(&self, old: &[V], new: &[V]) -> Vec<EntityId> {
+        let sort_keys = self.sort_keys.lock().unwrap();
+        let mut seen = HashSet::new();
+        old.iter()
+            .chain(new)
+            .filter(|item| seen.insert(item.entity().id()))
+            .filter(|item| sort_keys.get(&item.entity().id()).is_some_and(|key| *key != self.sort_key(item)))
+            .map(|item| item.entity().id())
+            .collect()
+    }
+
+    /// Array order of two items: display_order, oldest-first for DESC
+    fn compare_items(&self, a: &V, b: &V) -> std::cmp::Ordering {
+        let ordering = self
+            .display_order
+            .iter()
+            .map(|order_item| {
+                let (a, b) = (a.entity().value(order_item.path.first()), b.entity().value(order_item.path.first()));
+                let ordering = a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal);
+                match order_item.direction {
+                    OrderDirection::Asc => ordering,
+                    OrderDirection::Desc => ordering.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal);
+        if self.is_desc { ordering.reverse() } else { ordering }
+    }
+
+    /// Sort re-sorted items into place and drop those that moved past the outermost
+    /// unmoved item on the LIMIT side when more items exist there (unseen items may sort
+    /// between them). Items displaced by earlier calls count as moved. Returns the number
+    /// dropped.
+    fn reorder(&self, items: &mut Vec<V>, moved: &[EntityId], limit_at_front: bool, more_past_limit: bool) -> usize {
+        let mut displaced = self.displaced.lock().unwrap();
+        let previously_displaced = std::mem::take(&mut *displaced);
+        let is_moved = |item: &V| {
+            let id = item.entity().id();
+            moved.contains(&id) || previously_displaced.contains(&id)
+        };
+        if !items.iter().any(is_moved) {
+            return 0;
+        }
+        items.sort_by(|a, b| self.compare_items(a, b));
+        if !more_past_limit {
+            return 0;
+        }
+
+        let dropped: Vec<V> = if limit_at_front {
+            let edge = items.iter().position(|item| !is_moved(item)).unwrap_or(items.len());
+            items.drain(..edge).collect()
+        } else {
+            let edge = items.iter().rposition(|item| !is_moved(item)).map_or(0, |index| index + 1);
+            items.split_off(edge)
+        };
+        displaced.extend(dropped.iter().map(|item| item.entity().id()));
+        dropped.len()
+    }
+
+    /// Index in `items` of the nearest neighbour to hold the position when the slide's
+    /// anchor was re-sorted within the result while in flight (None if it wasn't, or no
+    /// neighbour stayed put). An anchor re-sorted out of the result is an intersection failure.
+    fn resorted_anchor_neighbor(&self, slide: &PendingSlide, items: &[V]) -> Option<usize> {
+        let anchor_key = slide.anchor_key.as_ref()?;
+        let order_item = self.display_order.first()?;
+        let anchor = items.iter().find(|item| item.entity().id() == slide.anchor)?;
+        if anchor.entity().value(order_item.path.first()).as_ref() == Some(anchor_key) {
+            return None;
+        }
+        slide.anchor_neighbors.iter().find_map(|(id, key)| {
+            let index = items.iter().position(|item| item.entity().id() == *id)?;
+            (self.sort_key(&items[index]) == *key).then_some(index)
+        })
+    }
+
+    /// Whether `id` left the window because it was deleted (no longer matches the predicate)
+    fn departed_deleted(&self, id: EntityId) -> bool {
+        self.departed.lock().unwrap().get(&id).is_some_and(|departed| departed.deleted)
+    }
+
+    /// Index of `id` in `items`, or of its nearest remaining neighbour if it left the window
     ///
-    /// # Arguments
-    /// * `first_visible` - EntityId of the first (oldest) visible item
-    /// * `last_visible` - EntityId of the last (newest) visible item
-    /// * `scrolling_backward` - True if user is scrolling toward older items
-    pub fn on_scroll(&self, first_visible: EntityId, last_visible: EntityId, scrolling_backward: bool) {
+    /// Neighbours are followed in the preferred direction first (`toward_following`), then
+    /// the other way, through any neighbours that also left.
+    fn resolve_departed(&self, id: EntityId, items: &[V], toward_following: bool) -> Option<usize> {
+        let position = |id: EntityId| items.iter().position(|item| item.entity().id() == id);
+        if let Some(index) = position(id) {
+            return Some(index);
+        }
+
+        let departed = self.departed.lock().unwrap();
+        for following in [toward_following, !toward_following] {
+            let step = |id: &EntityId| {
+                departed.get(id).and_then(|d| if following { d.following } else { d.preceding })
+            };
+            let mut next = step(&id);
+            // Bounded walk: each departed entry is visited at most once
+            for _ in 0..=departed.len() {
+                let Some(candidate) = next else { break };
+                if let Some(index) = position(candidate) {
+                    return Some(index);
+                }
+                next = step(&candidate);
+            }
+        }
+        None
+    }
+
+    /// Change the scroll mode, reporting transitions to the metrics hook
+    fn set_mode(&self, mode: ScrollMode) {
+        let previous = self.mode.peek();
+        if previous != mode {
+            if let Some(metrics) = &self.metrics {
+                metrics.mode_changed(previous, mode);
+            }
+        }
+        self.mode.set(mode);
+    }
+
+    /// Re-emit the current window with an error (flags only, no content change)
+    fn report_error(&self, error: ScrollError) {
+        let mut refreshed = self.visible_set.peek();
+        refreshed.intersection = None;
+        refreshed.intersection_failure = None;
+        refreshed.updated.clear();
+        refreshed.moved.clear();
+        refreshed.error = Some(error);
+        self.emit(refreshed);
+    }
+
+    /// Keep the pagination fields of debug_info current between scroll events
+    fn set_update_pending(&self, update_pending: bool, update_count: u32) {
+        let mut debug_info = self.debug_info.peek();
+        debug_info.update_pending = update_pending;
+        debug_info.update_count = update_count;
+        self.debug_info.set(debug_info);
+    }
+
+    // Computed properties
+    fn threshold(&self) -> f64 {
+        self.config.threshold_screens()
+    }
+
+    fn screen_items(&self) -> usize {
+        windowing::screen_items(self.viewport_height.load(std::sync::atomic::Ordering::Relaxed), self.minimum_row_height)
+    }
 
+    /// Current base filter
+    fn predicate(&self) -> Predicate {
+        self.predicate.lock().unwrap().clone()
+    }
+
+    fn live_window_size(&self) -> usize {
+        windowing::live_window_size(self.screen_items(), self.threshold())
+    }
+
+    /// Evaluate a scroll event, or queue it while a slide is in flight
+    fn on_scroll(&self, intent: ScrollIntent) {
+        if self.closed.peek() {
+            tracing::trace!("[on_scroll] closed, ignoring scroll event");
+            return;
+        }
+
+        // The in-flight result will replace the window these ids index into, so coalesce
+        // to the latest intent rather than issuing a competing query
+        if self.pending.peek().is_some() {
+            tracing::trace!("[on_scroll] slide pending, queueing scroll intent");
+            self.queued_scroll.set(Some(intent));
+            return;
+        }
+
+        // Hosts may report scrolls while mounting; there is no window to index into yet
+        if !self.initialized.peek() {
+            tracing::debug!("[on_scroll] called before start(), ignoring");
+            return;
+        }
+
+        let ScrollIntent { first_visible, last_visible, scrolling_backward, velocity } = intent;
         let current = self.visible_set.peek();
-        let screen = self.screen_items();
+        let prefetch = self.config.prefetch_items(velocity, self.minimum_row_height, self.screen_items());
+        let trigger = self.config.trigger_items(self.screen_items()) + prefetch;
 
         tracing::trace!(
             "[on_scroll] window: items={}, has_more_preceding={}, has_more_following={}",
             current.items.len(), current.has_more_preceding, current.has_more_following
         );
 
-        // Find indices of visible items in current window
-        let first_idx = current.items.iter().position(|item| item.entity().id() == first_visible);
-        let last_idx = current.items.iter().position(|item| item.entity().id() == last_visible);
+        // Find indices of visible items in current window. Ids of items that have since
+        // left it resolve to their nearest remaining neighbour inside the viewport.
+        let first_idx = self.resolve_departed(first_visible, &current.items, true);
+        let last_idx = self.resolve_departed(last_visible, &current.items, false);
 
         let (first_visible_index, last_visible_index) = match (first_idx, last_idx) {
-            (Some(f), Some(l)) => (f, l),
+            // Everything visible was removed: both edges resolve across the gap
+            (Some(f), Some(l)) => (f.min(l), f.max(l)),
             _ => {
                 tracing::warn!(
                     "[on_scroll] EARLY RETURN: EntityId not found! first_idx={:?}, last_idx={:?}",
@@ -471,11 +1457,13 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
         self.debug_info.set(ScrollDebugInfo {
             items_above,
             items_below,
-            trigger_threshold: screen,
+            trigger_threshold: trigger,
             first_visible_index,
             last_visible_index,
             update_count: self.update_count.load(std::sync::atomic::Ordering::Relaxed),
             update_pending: self.pending.peek().is_some(),
+            prefetch_distance: prefetch,
+            ..self.debug_info.peek()
         });
 
         tracing::trace!(
@@ -487,11 +1475,13 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
         // This makes "Jump to Current" button appear when user has scrolled enough to hide an item
         if self.mode.peek() == ScrollMode::Live && items_below > 0 {
             tracing::debug!("[on_scroll] Exiting Live mode (item scrolled off bottom, items_below={})", items_below);
-            self.mode.set(ScrollMode::Backward);
+            self.set_mode(ScrollMode::Backward);
             // Update visible_set to reflect mode change (shouldAutoScroll)
-            let mut updated = current.clone();
-            updated.should_auto_scroll = false;
-            self.visible_set.set(updated);
+            let mut refreshed = current.clone();
+            refreshed.should_auto_scroll = false;
+            refreshed.updated.clear(); // flags only, no content changed
+            refreshed.moved.clear();
+            self.emit(refreshed);
         }
 
         // Re-enter Live mode when scrolled back to the absolute bottom
@@ -499,26 +1489,28 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
         let at_bottom = !current.has_more_following && items_below == 0;
         if self.mode.peek() != ScrollMode::Live && at_bottom {
             tracing::debug!("[on_scroll] Re-entering Live mode (scrolled to bottom)");
-            self.mode.set(ScrollMode::Live);
+            self.set_mode(ScrollMode::Live);
             // Update visible_set to reflect mode change (shouldAutoScroll)
-            let mut updated = current.clone();
-            updated.should_auto_scroll = true;
-            self.visible_set.set(updated);
+            let mut refreshed = current.clone();
+            refreshed.should_auto_scroll = true;
+            refreshed.updated.clear(); // flags only, no content changed
+            refreshed.moved.clear();
+            self.emit(refreshed);
         }
 
         // Check thresholds for pagination
-        let backward_threshold = scrolling_backward && items_above <= screen && current.has_more_preceding;
-        let forward_threshold = !scrolling_backward && items_below <= screen && current.has_more_following;
+        let backward_threshold = scrolling_backward && items_above <= trigger && current.has_more_preceding;
+        let forward_threshold = !scrolling_backward && items_below <= trigger && current.has_more_following;
 
-        // Trigger when buffer is at or below S items (one screenful remaining)
+        // Trigger when buffer is at or below the trigger threshold (default one screenful remaining)
         if backward_threshold {
             tracing::debug!("[on_scroll] TRIGGERING BACKWARD PAGINATION");
-            self.mode.set(ScrollMode::Backward);
-            self.slide_window(&current, first_visible_index, last_visible_index, LoadDirection::Backward);
+            self.set_mode(ScrollMode::Backward);
+            self.slide_window(&current, first_visible_index, last_visible_index, LoadDirection::Backward, prefetch);
         } else if forward_threshold {
             tracing::debug!("[on_scroll] TRIGGERING FORWARD PAGINATION");
-            self.mode.set(ScrollMode::Forward);
-            self.slide_window(&current, first_visible_index, last_visible_index, LoadDirection::Forward);
+            self.set_mode(ScrollMode::Forward);
+            self.slide_window(&current, first_visible_index, last_visible_index, LoadDirection::Forward, prefetch);
         }
     }
 
@@ -526,14 +1518,17 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
     ///
     /// - Backward: anchor on newest_visible, cursor B items newer, query older items
     /// - Forward: anchor on oldest_visible, cursor B items older, query newer items (reversed ORDER BY)
+    ///
+    /// `prefetch` extends the leading side (the direction of travel) beyond B.
     fn slide_window(
         &self,
         current: &VisibleSet<V>,
         oldest_visible_index: usize,
         newest_visible_index: usize,
         direction: LoadDirection,
+        prefetch: usize,
     ) {
-        let buffer = 2 * self.screen_items(); // B = 2S
+        let buffer = self.config.buffer_items(self.screen_items()); // B = buffer_screens * S
         let max_index = current.items.len().saturating_sub(1);
 
         // Direction-specific: cursor position, intersection anchor, and comparison operator
@@ -559,7 +1554,31 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
 
         // Limit: from cursor to far visible edge + buffer for new items
         let visible_span = newest_visible_index.saturating_sub(oldest_visible_index) + 1;
-        let limit = visible_span + 2 * buffer;
+        let mut limit = visible_span + 2 * buffer + prefetch;
+
+        // Cap the window by evicting from the far side: move the cursor toward the visible
+        // edge so fewer trailing items are kept, then (when the visible span alone is too
+        // large) into the visible items, keeping the edge the user is scrolling toward
+        let mut cursor_index = cursor_index;
+        let mut intersection_index = intersection_index;
+        let mut evicted = 0;
+        if let Some(max) = self.config.max_window_size {
+            let excess = limit.saturating_sub(max);
+            if excess > 0 {
+                let capped_cursor = match direction {
+                    LoadDirection::Backward => cursor_index.saturating_sub(excess).max(oldest_visible_index),
+                    LoadDirection::Forward => (cursor_index + excess).min(newest_visible_index),
+                };
+                evicted = capped_cursor.abs_diff(cursor_index);
+                cursor_index = capped_cursor;
+                // The anchor has to stay in the result
+                intersection_index = match direction {
+                    LoadDirection::Backward => intersection_index.min(cursor_index),
+                    LoadDirection::Forward => intersection_index.max(cursor_index),
+                };
+                limit = max;
+            }
+        }
 
         // Get continuation item (cursor for debouncing) and anchor item (visible edge for scroll stability)
         let continuation = current.items.get(cursor_index)
@@ -572,7 +1591,7 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
         // Debounce: skip if user hasn't scrolled T items since last trigger
         // Track the oldest_visible position to measure actual user scroll distance
         // (not cursor position, which moves with the sliding window)
-        let threshold = self.screen_items(); // T = S items
+        let threshold = self.config.debounce_distance(self.screen_items()); // T (default S items)
         let oldest_visible_entity = current.items.get(oldest_visible_index)
             .map(|item| item.entity().id());
 
@@ -599,11 +1618,7 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
             if let Some(l_idx) = last_idx {
                 // Distance = how many items user has scrolled since last trigger
                 // For backward scroll: current oldest_visible_index < last trigger's oldest index
-                let distance = if oldest_visible_index < l_idx {
-                    l_idx - oldest_visible_index
-                } else {
-                    oldest_visible_index - l_idx
-                };
+                let distance = oldest_visible_index.abs_diff(l_idx);
                 tracing::trace!(
                     "[slide_window] distance={}, threshold={} (l_idx={}, oldest_visible_idx={})",
                     distance, threshold, l_idx, oldest_visible_index
@@ -613,6 +1628,9 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
                         "[slide_window] DEBOUNCE: scrolled {} items < threshold {}, SKIPPING",
                         distance, threshold
                     );
+                    if let Some(metrics) = &self.metrics {
+                        metrics.trigger_debounced(direction, distance, threshold);
+                    }
                     return;
                 }
                 tracing::trace!(
@@ -641,19 +1659,15 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
             self.last_trigger_oldest_visible.set(Some(entity));
         }
 
-        // Increment update counter
-        self.update_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-
-        self.pending.set(Some(PendingSlide {
-            continuation,
-            anchor,
-            limit,
-            direction,
-            reversed_order,
-        }));
-
         // Build cursor-constrained predicate
-        let predicate = self.build_cursor_predicate(current, cursor_index, operator);
+        let predicate = match self.build_cursor_predicate(current, cursor_index, operator) {
+            Ok(predicate) => predicate,
+            Err(e) => {
+                tracing::error!("[slide_window] {}", e);
+                self.report_error(e);
+                return;
+            }
+        };
 
         // Build ORDER BY (reversed for forward pagination)
         let order_by = if reversed_order {
@@ -674,18 +1688,266 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
             limit: Some((limit + 1) as u64), // +1 to detect has_more
         };
 
-        // Debug: log first and last item timestamps to verify array ordering
-        let first_ts = current.items.first().and_then(|i| i.entity().value("timestamp"));
-        let last_ts = current.items.last().and_then(|i| i.entity().value("timestamp"));
+        // Debug: log first and last item sort keys to verify array ordering
+        let first_key = current.items.first().map(|item| self.sort_key(item));
+        let last_key = current.items.last().map(|item| self.sort_key(item));
         tracing::trace!(
-            "[slide_window] cursor_index={}, oldest_vis={}, newest_vis={}, max={}, limit={}, first_ts={:?}, last_ts={:?}",
-            cursor_index, oldest_visible_index, newest_visible_index, max_index, limit, first_ts, last_ts
+            "[slide_window] cursor_index={}, oldest_vis={}, newest_vis={}, max={}, limit={}, first_key={:?}, last_key={:?}",
+            cursor_index, oldest_visible_index, newest_visible_index, max_index, limit, first_key, last_key
         );
         tracing::debug!("[slide_window] update_selection: {}", selection);
 
+        let anchor_key = self.display_order.first()
+            .and_then(|order_item| current.items[intersection_index].entity().value(order_item.path.first()));
+        // Neighbours at the anchor's viewport edge come first: older for a backward slide
+        let reach = self.screen_items();
+        let preceding = (intersection_index.saturating_sub(reach)..intersection_index).rev();
+        let following = intersection_index + 1..=(intersection_index + reach).min(max_index);
+        let neighbor_indices: Vec<usize> = match direction {
+            LoadDirection::Backward => preceding.chain(following).collect(),
+            LoadDirection::Forward => following.chain(preceding).collect(),
+        };
+        let anchor_neighbors = neighbor_indices
+            .into_iter()
+            .map(|index| (current.items[index].entity().id(), self.sort_key(&current.items[index])))
+            .collect();
+        self.issue_slide(selection, PendingSlide {
+            continuation,
+            anchor,
+            limit,
+            direction,
+            reversed_order,
+            version: 0,
+            anchor_key,
+            to_live: false,
+            reaches_live_edge: direction == LoadDirection::Backward
+                && !current.has_more_following
+                && cursor_index == max_index,
+            recovering: None,
+            deleted: 0,
+            anchor_neighbors,
+            evicted,
+            issued_at: None,
+        });
+    }
+
+    /// Issue a slide's query and mark it pending; false if the selection was rejected
+    fn issue_slide(&self, selection: Selection, mut slide: PendingSlide) -> bool {
+        let update_count = self.update_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+
         if let Err(e) = self.livequery.update_selection(selection) {
             tracing::error!("[slide_window] FAILED to update selection: {}", e);
+            self.report_error(ScrollError::QueryFailed(e.to_string()));
+            return false;
         }
+
+        // Record the slide against the selection version just issued (results are
+        // delivered asynchronously, after this returns)
+        let (_, version) = self.livequery.selection().peek();
+        slide.version = version;
+        slide.issued_at = Some(web_time::Instant::now());
+        if let Some(metrics) = &self.metrics {
+            metrics.slide_issued(slide.direction, slide.limit);
+        }
+        self.pending.set(Some(slide));
+        self.set_update_pending(true, update_count);
+        self.watch_unnotified(version);
+
+        // Flags only: let the renderer show a spinner at the edge being loaded. The last
+        // window's intersection and error are kept, so a renderer that only reads the
+        // latest value still anchors a slide that landed just before this one was issued
+        let mut refreshed = self.visible_set.peek();
+        (refreshed.loading_preceding, refreshed.loading_following) = self.loading_edges();
+        refreshed.updated.clear();
+        refreshed.moved.clear();
+        self.emit(refreshed);
+        true
+    }
+
+    /// Land the slide issued as selection `version` if its result loads without a notification
+    ///
+    /// The livequery only notifies for items added or dropped by the predicate: a result
+    /// with the same items as the last one (a slide at the data boundary, a reload that
+    /// matches the window) or one that a smaller LIMIT only trims would otherwise leave
+    /// the slide pending. A notification that arrives after this lands the slide carries
+    /// nothing new, and the subscription skips it.
+    fn watch_unnotified(&self, version: u32) {
+        let (livequery, closed, this) = (self.livequery.clone(), self.close_rx.clone(), self.this.clone());
+        ankurah::core::task::spawn(async move {
+            let loaded = std::pin::pin!(livequery.wait_initialized());
+            if let Either::Left(_) = futures::future::select(loaded, closed).await {
+                if let Some(state) = this.upgrade() {
+                    state.land_unnotified(version);
+                }
+            }
+        });
+    }
+
+    fn land_unnotified(&self, version: u32) {
+        let _landing = self.landing.lock().unwrap();
+        let (_, current) = self.livequery.selection().peek();
+        let waiting = self.pending.peek().is_some_and(|slide| slide.version == version);
+        // Superseded by a newer selection, or already landed by a notification
+        if current != version || !waiting || self.closed.peek() {
+            return;
+        }
+        // wait_initialized also returns for an earlier selection's activation
+        if !self.livequery.loaded() {
+            self.watch_unnotified(version);
+            return;
+        }
+        tracing::debug!("[land_unnotified] result of selection {} loaded, applying it", version);
+        self.apply_changeset(ChangeSet { resultset: self.livequery.resultset(), changes: Vec::new() });
+    }
+
+    /// Whether every membership change in `changeset` is already reflected in the last
+    /// applied result (its slide was landed by `land_unnotified` first)
+    fn already_applied(&self, changeset: &ChangeSet<V>) -> bool {
+        let notified = self.notified_ids.lock().unwrap();
+        changeset.changes.iter().all(|change| match change {
+            ItemChange::Initial { item } | ItemChange::Add { item, .. } => notified.contains(&item.entity().id()),
+            ItemChange::Remove { item, .. } => !notified.contains(&item.entity().id()),
+            ItemChange::Update { .. } => false,
+        })
+    }
+
+    /// Edges with a slide in flight: (preceding, following)
+    fn loading_edges(&self) -> (bool, bool) {
+        match self.pending.peek() {
+            Some(slide) if slide.to_live => (false, true),
+            Some(slide) => (slide.direction == LoadDirection::Backward, slide.direction == LoadDirection::Forward),
+            None => (false, false),
+        }
+    }
+
+    /// Apply the configured recovery to a slide whose anchor is missing from `items`
+    fn recover_intersection(&self, slide: &PendingSlide, items: &[V]) -> Recovered {
+        let failure = |recovery| IntersectionFailure { anchor: slide.anchor, direction: slide.direction, recovery };
+        let strategy = match self.config.intersection_recovery {
+            // A retry that still misses the anchor re-centres instead of widening again
+            RecoveryStrategy::RetryWider if slide.recovering.is_some() => RecoveryStrategy::NearestNeighbor,
+            strategy => strategy,
+        };
+
+        match strategy {
+            RecoveryStrategy::Report => return Recovered::Unanchored(failure(RecoveryAction::None)),
+            RecoveryStrategy::RetryWider => {
+                let wider = (slide.limit * 2).min(self.config.max_window_size.unwrap_or(usize::MAX));
+                if wider > slide.limit {
+                    let (mut selection, _) = self.livequery.selection().peek();
+                    selection.limit = Some((wider + 1) as u64); // +1 to detect has_more
+                    tracing::debug!("[recover] retrying slide with limit {}", wider);
+                    let retry = PendingSlide {
+                        limit: wider,
+                        recovering: Some(failure(RecoveryAction::RetriedWider)),
+                        deleted: 0,
+                        ..slide.clone()
+                    };
+                    if self.issue_slide(selection, retry) {
+                        return Recovered::Reissued;
+                    }
+                }
+            }
+            RecoveryStrategy::JumpToLive if slide.reaches_live_edge => {
+                tracing::debug!("[recover] result holds the live window, trimming in place");
+                if self.select_live_in_place() {
+                    return Recovered::Live(failure(RecoveryAction::JumpedToLive));
+                }
+            }
+            RecoveryStrategy::JumpToLive => {
+                tracing::debug!("[recover] reloading the live window");
+                if self.reload_live(Some(failure(RecoveryAction::JumpedToLive))) {
+                    return Recovered::Reissued;
+                }
+            }
+            RecoveryStrategy::NearestNeighbor => {}
+        }
+
+        let nearest = slide.anchor_key.as_ref().and_then(|key| self.nearest_by_key(key, items));
+        match nearest {
+            Some(index) => {
+                let entity_id = items[index].entity().id();
+                tracing::debug!("[recover] re-centring on nearest neighbour {:?} at index {}", entity_id, index);
+                Recovered::Anchored(
+                    Intersection { entity_id, index, direction: slide.direction },
+                    failure(RecoveryAction::NearestNeighbor(entity_id)),
+                )
+            }
+            None => Recovered::Unanchored(failure(RecoveryAction::None)),
+        }
+    }
+
+    /// Index of the item whose first ORDER BY value is nearest `key`: the closest at or
+    /// below it, otherwise the closest above it
+    fn nearest_by_key(&self, key: &Value, items: &[V]) -> Option<usize> {
+        let field = self.display_order.first()?.path.first();
+        let mut below: Option<(usize, Value)> = None;
+        let mut above: Option<(usize, Value)> = None;
+        for (index, item) in items.iter().enumerate() {
+            let Some(value) = item.entity().value(field) else { continue };
+            match value.partial_cmp(key) {
+                Some(std::cmp::Ordering::Greater) if above.as_ref().is_none_or(|(_, best)| value < *best) => {
+                    above = Some((index, value));
+                }
+                Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal)
+                    if below.as_ref().is_none_or(|(_, best)| value > *best) =>
+                {
+                    below = Some((index, value));
+                }
+                _ => {}
+            }
+        }
+        below.or(above).map(|(index, _)| index)
+    }
+
+    /// Query for the live window, fetching limit+1 to detect older items
+    fn live_selection(&self, limit: usize) -> Selection {
+        Selection {
+            predicate: self.predicate(),
+            order_by: Some(self.display_order.clone()),
+            limit: Some((limit + 1) as u64), // +1 to detect has_more
+        }
+    }
+
+    /// Narrow the query to the live window when the current result already holds it
+    ///
+    /// No query result follows (the livequery only notifies on membership changes), so
+    /// the caller trims the current items itself.
+    fn select_live_in_place(&self) -> bool {
+        let limit = self.live_window_size();
+        if let Err(e) = self.livequery.update_selection(self.live_selection(limit)) {
+            tracing::error!("[select_live_in_place] FAILED to update selection: {}", e);
+            self.report_error(ScrollError::QueryFailed(e.to_string()));
+            return false;
+        }
+        self.active_slide.set(Some(ActiveQuery { limit, direction: LoadDirection::Backward, reversed_order: false, deleted: 0 }));
+        true
+    }
+
+    /// Replace the window with the live edge (newest live-window items)
+    fn reload_live(&self, recovering: Option<IntersectionFailure>) -> bool {
+        let limit = self.live_window_size();
+        let selection = self.live_selection(limit);
+        // Not used to position a live reload; only identifies the slide in logs
+        let anchor = recovering.as_ref().map(|failure| failure.anchor).unwrap_or_else(|| {
+            self.visible_set.peek().items.last().map(|item| item.entity().id()).unwrap_or_default()
+        });
+        self.issue_slide(selection, PendingSlide {
+            continuation: anchor,
+            anchor,
+            limit,
+            direction: LoadDirection::Backward,
+            reversed_order: false,
+            version: 0,
+            anchor_key: None,
+            to_live: true,
+            reaches_live_edge: true,
+            recovering,
+            deleted: 0,
+            anchor_neighbors: Vec::new(),
+            evicted: 0,
+            issued_at: None,
+        })
     }
 
     /// Build a predicate constrained by cursor: `base AND field OP cursor_value`
@@ -694,16 +1956,20 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
         current: &VisibleSet<V>,
         cursor_index: usize,
         operator: ComparisonOperator,
-    ) -> Predicate {
+    ) -> Result<Predicate, ScrollError> {
         let Some(cursor_item) = current.items.get(cursor_index) else {
-            return self.predicate.clone();
+            return Ok(self.predicate());
         };
         let Some(order_item) = self.display_order.first() else {
-            return self.predicate.clone();
+            return Ok(self.predicate());
         };
         let field_name = order_item.path.first();
         let Some(cursor_value) = cursor_item.entity().value(field_name) else {
-            return self.predicate.clone();
+            return Err(ScrollError::CursorConversionFailed(format!(
+                "cursor item {} has no value for '{}'",
+                cursor_item.entity().id(),
+                field_name
+            )));
         };
 
         // Debug: log the cursor item's ID and timestamp
@@ -718,13 +1984,13 @@ impl<V: View + Clone + Send + Sync + 'static> ScrollManager<V> {
         let cursor_predicate = Predicate::Comparison {
             left: Box::new(Expr::Path(PathExpr::simple(field_name))),
             operator,
-            right: Box::new(Expr::Literal(value_to_literal(&cursor_value))),
+            right: Box::new(Expr::Literal(value_to_literal(&cursor_value)?)),
         };
 
-        Predicate::And(
-            Box::new(self.predicate.clone()),
+        Ok(Predicate::And(
+            Box::new(self.predicate()),
             Box::new(cursor_predicate),
-        )
+        ))
     }
 }
 
@@ -0,0 +1,47 @@
+//! Scroll Metrics
+//!
+//! Hooks for observing pagination in production: how long slides take, how much they
+//! fetch, and how often triggers are debounced or intersections fail. Install an
+//! implementation with `ScrollManagerBuilder::metrics`; every method defaults to a no-op,
+//! so implementations only override what they record.
+
+use std::time::Duration;
+
+use crate::{IntersectionFailure, LoadDirection, ScrollMode};
+
+/// A slide whose result has landed
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlideMetrics {
+    /// Direction of the slide (`Backward` for live window reloads)
+    pub direction: LoadDirection,
+    /// Time from `update_selection` to the loaded result
+    pub latency: Duration,
+    /// Items in the loaded result, including the limit+1 sentinel
+    pub items_fetched: usize,
+    /// Reload of the live window rather than a cursor slide
+    pub to_live: bool,
+    /// Re-issued to recover from an intersection failure
+    pub recovery: bool,
+}
+
+/// Receiver for pagination events
+///
+/// Called synchronously from scroll events and livequery callbacks, so implementations
+/// should only record (e.g. increment a counter or push to a histogram).
+pub trait ScrollMetrics: Send + Sync {
+    /// A slide's query was issued with this limit (excluding the sentinel)
+    fn slide_issued(&self, _direction: LoadDirection, _limit: usize) {}
+
+    /// A slide's result landed
+    fn slide_completed(&self, _slide: &SlideMetrics) {}
+
+    /// A threshold was crossed but the user hadn't scrolled `threshold` items since the
+    /// last trigger, so no slide was issued
+    fn trigger_debounced(&self, _direction: LoadDirection, _distance: usize, _threshold: usize) {}
+
+    /// A slide's anchor was missing from its result (reported once the recovery lands)
+    fn intersection_failed(&self, _failure: &IntersectionFailure) {}
+
+    /// The scroll mode changed
+    fn mode_changed(&self, _from: ScrollMode, _to: ScrollMode) {}
+}
@@ -0,0 +1,70 @@
+//! Async access to the visible set for Rust consumers that don't render through signals
+//! (Tokio services, TUI apps, tests)
+
+use crate::VisibleSet;
+use ankurah_signals::SubscriptionGuard;
+use futures::channel::{mpsc::UnboundedReceiver, oneshot};
+use futures::future::Shared;
+use futures::{FutureExt, Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Visible set emissions as a `Stream`, from `ScrollManager::visible_set_stream()`
+///
+/// Yields the visible set current when the stream was created, then later emissions in
+/// order. Notifications are buffered, so a slow consumer still sees each one. The signal
+/// hands a notification the value current at delivery, so an emission overtaken by the
+/// next arrives as that one, twice; the stream yields each emission once. It ends once
+/// the manager is closed or dropped, after the emissions made before that. Dropping the
+/// stream unsubscribes.
+pub struct VisibleSetStream<V> {
+    initial: Option<VisibleSet<V>>,
+    /// `VisibleSet::emission` of the value last yielded
+    last: Option<u64>,
+    rx: UnboundedReceiver<VisibleSet<V>>,
+    /// Resolves on `close()`, or is cancelled when the manager is dropped
+    closed: Option<Shared<oneshot::Receiver<()>>>,
+    subscription: Option<SubscriptionGuard>,
+}
+
+impl<V> VisibleSetStream<V> {
+    pub(crate) fn new(
+        initial: VisibleSet<V>,
+        rx: UnboundedReceiver<VisibleSet<V>>,
+        subscription: SubscriptionGuard,
+        closed: Shared<oneshot::Receiver<()>>,
+    ) -> Self {
+        Self { initial: Some(initial), last: None, rx, closed: Some(closed), subscription: Some(subscription) }
+    }
+}
+
+// Nothing is pinned structurally: the fields are only moved out of or polled through
+// `Unpin` receivers
+impl<V> Unpin for VisibleSetStream<V> {}
+
+impl<V> Stream for VisibleSetStream<V> {
+    type Item = VisibleSet<V>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(initial) = self.initial.take() {
+            self.last = Some(initial.emission);
+            return Poll::Ready(Some(initial));
+        }
+        if self.closed.as_mut().is_some_and(|closed| closed.poll_unpin(cx).is_ready()) {
+            // Unsubscribing drops the sender: the receiver yields what was already sent,
+            // then ends
+            self.closed = None;
+            self.subscription = None;
+        }
+        loop {
+            match self.rx.poll_next_unpin(cx) {
+                Poll::Ready(Some(vs)) if self.last == Some(vs.emission) => continue,
+                Poll::Ready(Some(vs)) => {
+                    self.last = Some(vs.emission);
+                    return Poll::Ready(Some(vs));
+                }
+                other => return other,
+            }
+        }
+    }
+}
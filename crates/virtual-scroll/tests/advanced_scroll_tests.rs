@@ -140,6 +140,7 @@ async fn test_rapid_pagination_triggers() -> Result<(), anyhow::Error> {
 /// Test that intersection item exists in both old and new windows.
 /// Backward pagination: intersection at newest visible (bottom of viewport).
 #[tokio::test]
+#[allow(clippy::manual_range_contains)]
 async fn test_intersection_anchoring_backward() -> Result<(), anyhow::Error> {
     let ctx = durable_sled_setup().await?;
     create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
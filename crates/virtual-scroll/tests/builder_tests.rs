@@ -0,0 +1,141 @@
+//! ScrollManager builder tests
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, live window = 30).
+
+mod common;
+
+use ankurah_signals::Peek;
+use ankurah_virtual_scroll::{ScrollConfig, ScrollMode};
+use common::*;
+use std::sync::Arc;
+
+/// The builder with defaults behaves like `new()` with buffer_factor 2.0.
+#[tokio::test]
+async fn test_builder_defaults_match_new() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(
+        ScrollManager::<TestMessageView>::builder(&ctx)
+            .order_by("timestamp DESC")
+            .viewport(500)
+            .row_height(50)
+            .build()?,
+    );
+    let mut r = MockRenderer::new(sm.clone(), 500);
+    tokio::spawn({
+        let sm = sm.clone();
+        async move { sm.start().await }
+    });
+
+    let vs = r.next_render().await?;
+    r.assert(&vs, 30, 1030..=1059, None, true, false, true, 1050, 1059);
+    assert_eq!(sm.current_selection(), "TRUE ORDER BY timestamp DESC LIMIT 30");
+    assert_eq!(sm.mode(), ScrollMode::Live);
+
+    Ok(())
+}
+
+/// Missing or invalid options are reported from build() rather than panicking.
+#[tokio::test]
+async fn test_builder_validation() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    let builder = || {
+        ScrollManager::<TestMessageView>::builder(&ctx)
+            .order_by("timestamp DESC")
+            .viewport(500)
+            .row_height(50)
+    };
+
+    assert!(builder().build().is_ok());
+    assert!(ScrollManager::<TestMessageView>::builder(&ctx).viewport(500).row_height(50).build().is_err());
+    assert!(builder().order_by("timestamp SIDEWAYS").build().is_err());
+    assert!(builder().predicate("timestamp >").build().is_err());
+    assert!(builder().viewport(0).build().is_err());
+    assert!(builder().row_height(0).build().is_err());
+    assert!(builder().buffer(1.0).build().is_ok(), "buffer factor is clamped to 2.0 as in new()");
+    assert!(
+        builder().config(ScrollConfig::default().buffer_screens(1.0)).build().is_err(),
+        "buffer must exceed the trigger threshold"
+    );
+    assert!(builder().config(ScrollConfig::default().debounce_items(0)).build().is_err());
+
+    Ok(())
+}
+
+/// Predicate, buffer and config options flow through to the query.
+#[tokio::test]
+async fn test_builder_options() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = ScrollManager::<TestMessageView>::builder(&ctx)
+        .predicate("timestamp >= 1010")
+        .order_by("timestamp DESC")
+        .viewport(500)
+        .row_height(50)
+        .buffer(4.0)
+        .build()?;
+    sm.start().await;
+
+    // Live window = (2 * 2 + 1) * 10 = 50, but only 1010-1059 match the predicate
+    assert_eq!(
+        sm.current_selection(),
+        "\"timestamp\" >= 1010 ORDER BY timestamp DESC LIMIT 50"
+    );
+    assert_eq!(timestamps(&sm.visible_set().peek()), (1010..=1059).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+/// start_at opens the window mid-history with the start item anchored at the bottom.
+#[tokio::test]
+async fn test_builder_start_at() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..100).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(
+        ScrollManager::<TestMessageView>::builder(&ctx)
+            .order_by("timestamp DESC")
+            .viewport(500)
+            .row_height(50)
+            .start_at(1049i64)
+            .build()?,
+    );
+    let mut r = MockRenderer::new(sm.clone(), 500);
+    tokio::spawn({
+        let sm = sm.clone();
+        async move { sm.start().await }
+    });
+
+    // 30 items at or before 1049 (limit+1 sentinel trimmed), anchored on 1049
+    let vs = r.next_render().await?;
+    r.assert(&vs, 30, 1020..=1049, Some(1049), true, true, false, 1040, 1049);
+    assert_eq!(r.scroll_offset, 1000);
+    assert_eq!(sm.mode(), ScrollMode::Backward);
+    assert_eq!(
+        sm.current_selection(),
+        "TRUE AND \"timestamp\" <= 1049 ORDER BY timestamp DESC LIMIT 31"
+    );
+
+    // Nothing below the viewport and more items following → forward slide toward live
+    // cursor = 20 - 20 = 0 → ts 1020, 50 items returned (1020-1069), anchored on the
+    // first visible item
+    r.scroll_down_and_expect(
+        0,
+        50,
+        1020..=1069,
+        Some(1040),
+        true,
+        true,
+        false,
+        1040,
+        1049,
+        1000,
+        Some("TRUE AND \"timestamp\" >= 1020 ORDER BY timestamp ASC LIMIT 51"),
+    )
+    .await?;
+    assert_eq!(sm.mode(), ScrollMode::Forward);
+
+    Ok(())
+}
@@ -28,6 +28,8 @@ pub use ankurah_virtual_scroll::{ScrollManager, VisibleSet};
 pub struct TestMessage {
     pub timestamp: i64,
     pub height: i32,
+    /// Soft-delete flag (tests that delete items filter on `deleted = false`)
+    pub deleted: bool,
 }
 
 // Initialize tracing for tests
@@ -65,13 +67,50 @@ pub async fn create_messages(
     let trx = ctx.begin();
     let mut ids = Vec::new();
     for (timestamp, height) in messages {
-        let msg = trx.create(&TestMessage { timestamp, height }).await?;
+        let msg = trx.create(&TestMessage { timestamp, height, deleted: false }).await?;
         ids.push(msg.id());
     }
     trx.commit().await?;
     Ok(ids)
 }
 
+/// Edit a test message's height in place (does not affect display order)
+pub async fn set_height(
+    ctx: &Context,
+    item: &TestMessageView,
+    height: i32,
+) -> Result<(), MutationError> {
+    let trx = ctx.begin();
+    trx.edit::<TestMessage>(item.entity())?.height().set(&height)?;
+    trx.commit().await?;
+    Ok(())
+}
+
+/// Soft-delete test messages (they drop out of `deleted = false` queries)
+pub async fn soft_delete(
+    ctx: &Context,
+    items: &[TestMessageView],
+) -> Result<(), MutationError> {
+    let trx = ctx.begin();
+    for item in items {
+        trx.edit::<TestMessage>(item.entity())?.deleted().set(&true)?;
+    }
+    trx.commit().await?;
+    Ok(())
+}
+
+/// Change a test message's timestamp (moves it in display order)
+pub async fn set_timestamp(
+    ctx: &Context,
+    item: &TestMessageView,
+    timestamp: i64,
+) -> Result<(), MutationError> {
+    let trx = ctx.begin();
+    trx.edit::<TestMessage>(item.entity())?.timestamp().set(&timestamp)?;
+    trx.commit().await?;
+    Ok(())
+}
+
 /// Extract timestamps from a VisibleSet
 pub fn timestamps<V: ankurah::model::View>(visible_set: &VisibleSet<V>) -> Vec<i64> {
     visible_set
@@ -157,6 +196,8 @@ impl<V: View + Clone + Send + Sync + 'static> MockRenderer<V> {
 
     /// Process a render and update local state (shared by next_render and collect_renders).
     fn process_render(&mut self, vs: &VisibleSet<V>) {
+        let items_changed = !vs.items.iter().map(|item| item.entity().id()).eq(self.item_ids.iter().copied());
+
         // Cache item data
         self.prev_item_count = self.item_heights.len();
         self.item_heights = vs
@@ -185,7 +226,9 @@ impl<V: View + Clone + Send + Sync + 'static> MockRenderer<V> {
         // Adjust scroll position based on intersection
         if vs.should_auto_scroll {
             self.scroll_offset = (self.content_height - self.viewport_height).max(0);
-        } else if let Some(ref intersection) = vs.intersection {
+        } else if let Some(intersection) = vs.intersection.as_ref().filter(|_| items_changed) {
+            // A flags-only emission (same items) repeats the last intersection; like a
+            // renderer anchoring against its last layout, nothing moves
             use ankurah_virtual_scroll::LoadDirection;
             match intersection.direction {
                 LoadDirection::Forward => {
@@ -219,13 +262,28 @@ impl<V: View + Clone + Send + Sync + 'static> MockRenderer<V> {
             .ok_or(MockRendererError("channel closed"))?;
 
         if let Some(ref err) = vs.error {
-            return Err(MockRendererError(Box::leak(err.clone().into_boxed_str())));
+            return Err(MockRendererError(Box::leak(err.to_string().into_boxed_str())));
         }
 
         self.process_render(&vs);
         Ok(vs)
     }
 
+    /// Wait for the next render that isn't a slide's loading emission.
+    ///
+    /// Issuing a slide re-emits the current window with a loading flag set; each such
+    /// emission is checked to leave the window unchanged and applied before waiting on.
+    pub async fn next_window_render(&mut self) -> Result<VisibleSet<V>, MockRendererError> {
+        loop {
+            let previous = self.item_ids.clone();
+            let vs = self.next_render().await?;
+            if !(vs.loading_preceding || vs.loading_following) {
+                return Ok(vs);
+            }
+            assert_eq!(self.item_ids, previous, "loading emission changed the window");
+        }
+    }
+
     /// Compute which item indices are currently visible in the viewport.
     ///
     /// - First visible: first item with pixels in viewport (bottom edge past viewport top)
@@ -323,6 +381,19 @@ impl<V: View + Clone + Send + Sync + 'static> MockRenderer<V> {
         self.collect_renders(100).await
     }
 
+    /// Scroll up by `px` pixels at `velocity` pixels/second and collect all resulting renders.
+    pub async fn scroll_up_collect_with_velocity(&mut self, px: i32, velocity: f64) -> Vec<VisibleSet<V>> {
+        self.scroll_offset = (self.scroll_offset - px).max(0);
+        let (first_idx, last_idx) = self.visible_indices();
+        if let (Some(&first), Some(&last)) =
+            (self.item_ids.get(first_idx), self.item_ids.get(last_idx))
+        {
+            self.sm.on_scroll_with_velocity(first, last, true, velocity);
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        self.collect_renders(100).await
+    }
+
     /// Scroll down by `px` pixels and collect all resulting renders.
     pub async fn scroll_down_collect(&mut self, px: i32) -> Vec<VisibleSet<V>> {
         let max_offset = (self.content_height - self.viewport_height).max(0);
@@ -374,7 +445,7 @@ impl<V: View + Clone + Send + Sync + 'static> MockRenderer<V> {
         }
         // 500ms timeout - if render doesn't arrive, crash
         let vs =
-            match tokio::time::timeout(std::time::Duration::from_millis(500), self.next_render())
+            match tokio::time::timeout(std::time::Duration::from_millis(500), self.next_window_render())
                 .await
             {
                 Ok(result) => result?,
@@ -540,7 +611,7 @@ impl<V: View + Clone + Send + Sync + 'static> MockRenderer<V> {
         }
         // 500ms timeout - if render doesn't arrive, crash
         let vs =
-            match tokio::time::timeout(std::time::Duration::from_millis(500), self.next_render())
+            match tokio::time::timeout(std::time::Duration::from_millis(500), self.next_window_render())
                 .await
             {
                 Ok(result) => result?,
@@ -0,0 +1,189 @@
+//! Deletion tests
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30).
+//! Items are soft-deleted (`deleted = true`) under a `deleted = false` predicate, the way
+//! another peer's deletion reaches the window.
+
+mod common;
+
+use ankurah_signals::Peek;
+use ankurah_virtual_scroll::{LoadDirection, RecoveryStrategy, ScrollConfig};
+use common::*;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+type Renders = mpsc::UnboundedReceiver<VisibleSet<TestMessageView>>;
+
+struct Setup {
+    ctx: ankurah::Context,
+    sm: Arc<ScrollManager<TestMessageView>>,
+    rx: Renders,
+    _guard: ankurah_signals::SubscriptionGuard,
+}
+
+/// Report-only recovery, so a deleted anchor can't be mistaken for a recovered failure
+async fn setup(count: i64) -> Result<Setup, anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..count).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(
+        ScrollManager::<TestMessageView>::builder(&ctx)
+            .predicate("deleted = false")
+            .order_by("timestamp DESC")
+            .viewport(500)
+            .row_height(50)
+            .config(ScrollConfig::default().intersection_recovery(RecoveryStrategy::Report))
+            .build()?,
+    );
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set().subscribe(tx);
+    sm.start().await;
+    while rx.try_recv().is_ok() {}
+    Ok(Setup { ctx, sm, rx, _guard })
+}
+
+fn item_at(vs: &VisibleSet<TestMessageView>, ts: i64) -> TestMessageView {
+    let index = timestamps(vs).iter().position(|t| *t == ts).expect("timestamp in window");
+    vs.items[index].clone()
+}
+
+/// Wait for a render carrying an intersection (a landed slide; loading emissions repeat
+/// the previous window's)
+async fn next_slide(rx: &mut Renders) -> VisibleSet<TestMessageView> {
+    loop {
+        let vs = tokio::time::timeout(Duration::from_millis(500), rx.recv())
+            .await
+            .expect("slide did not land within 500ms")
+            .expect("channel closed");
+        let loading = vs.loading_preceding || vs.loading_following;
+        if !loading && (vs.intersection.is_some() || vs.error.is_some()) {
+            return vs;
+        }
+    }
+}
+
+/// Scroll events naming deleted items resolve to the nearest remaining items in view.
+#[tokio::test]
+async fn test_deleted_visible_items_resolve_to_neighbors() -> Result<(), anyhow::Error> {
+    let mut s = setup(100).await?;
+    let vs = s.sm.visible_set().peek();
+    let (first, last) = (item_at(&vs, 1080), item_at(&vs, 1089));
+
+    soft_delete(&s.ctx, &[first.clone(), last.clone()]).await?;
+    let vs = tokio::time::timeout(Duration::from_millis(500), s.rx.recv()).await?.expect("render");
+    // The live window refills from older items: 1068-1099 without 1080 and 1089
+    let expected: Vec<i64> = (1068..=1099).filter(|ts| *ts != 1080 && *ts != 1089).collect();
+    assert_eq!(timestamps(&vs), expected);
+
+    // Renderer still reports the stale ids: first → 1081 (index 12), last → 1088 (index 19)
+    s.sm.on_scroll(first.entity().id(), last.entity().id(), true);
+    let debug = s.sm.debug_info().peek();
+    assert_eq!((debug.first_visible_index, debug.last_visible_index), (12, 19));
+    assert!(!debug.update_pending, "12 items above is outside the trigger zone");
+
+    // Scrolling on with a stale bottom id still triggers and anchors on the neighbour
+    s.sm.on_scroll(item_at(&vs, 1078).entity().id(), last.entity().id(), true);
+    let vs = next_slide(&mut s.rx).await;
+    assert!(vs.error.is_none());
+    let intersection = vs.intersection.clone().expect("anchored");
+    assert_eq!(intersection.entity_id, item_at(&vs, 1088).entity().id());
+
+    Ok(())
+}
+
+/// Deleting the anchor of an in-flight slide anchors on its older neighbour instead of
+/// failing the intersection.
+#[tokio::test]
+async fn test_anchor_deleted_mid_flight() -> Result<(), anyhow::Error> {
+    let mut s = setup(100).await?;
+    let vs = s.sm.visible_set().peek();
+    let anchor = item_at(&vs, 1089);
+
+    s.sm.on_scroll(item_at(&vs, 1080).entity().id(), anchor.entity().id(), true);
+    soft_delete(&s.ctx, std::slice::from_ref(&anchor)).await?;
+
+    let vs = next_slide(&mut s.rx).await;
+    assert!(vs.error.is_none(), "error={:?}", vs.error);
+    assert!(vs.intersection_failure.is_none());
+    assert!(!timestamps(&vs).contains(&1089));
+    let intersection = vs.intersection.clone().expect("anchored");
+    assert_eq!(intersection.entity_id, item_at(&vs, 1088).entity().id());
+    assert_eq!(intersection.direction, LoadDirection::Backward);
+
+    Ok(())
+}
+
+/// Deleting items while continuously scrolling toward the oldest edge never stalls or
+/// reports an error.
+#[tokio::test]
+async fn test_deletions_while_scrolling() -> Result<(), anyhow::Error> {
+    let s = setup(300).await?;
+
+    let mut steps = 0;
+    for step in 0..500 {
+        let mut vs = s.sm.visible_set().peek();
+        assert!(vs.error.is_none(), "error={:?}", vs.error);
+        if !vs.has_more_preceding {
+            // A slide can land before the livequery refills gaps left by a deletion
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            vs = s.sm.visible_set().peek();
+            if !vs.has_more_preceding {
+                break;
+            }
+        }
+        // Top screen visible; every few steps another peer deletes the top visible item
+        let (first, last) = (vs.items[0].clone(), vs.items[9].clone());
+        s.sm.on_scroll(first.entity().id(), last.entity().id(), true);
+        if step % 3 == 0 {
+            soft_delete(&s.ctx, &[first]).await?;
+        }
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        steps += 1;
+    }
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let vs = s.sm.visible_set().peek();
+    assert!(vs.error.is_none(), "error={:?}", vs.error);
+    assert!(!vs.has_more_preceding, "should reach the oldest edge");
+    assert!(!s.sm.debug_info().peek().update_pending);
+    // Each slide moves the window at most 20 items older: 1270 → 1000 takes many steps
+    assert!(steps >= 14, "steps={}", steps);
+
+    Ok(())
+}
+
+/// Departures past the cap (4 live windows) evict the oldest entries, so a deletion
+/// recorded just before a slide still resolves after many slides.
+#[tokio::test]
+async fn test_departures_keep_most_recent() -> Result<(), anyhow::Error> {
+    let mut s = setup(400).await?;
+
+    // The first slide grows the window to 50; each later one departs 20 newer items,
+    // filling the cap of 120 entries
+    for _ in 0..7 {
+        let vs = s.sm.visible_set().peek();
+        s.sm.on_scroll(vs.items[0].entity().id(), vs.items[9].entity().id(), true);
+        next_slide(&mut s.rx).await;
+    }
+
+    let vs = s.sm.visible_set().peek();
+    let deleted = vs.items[5].clone();
+    let following = vs.items[6].entity().id();
+    soft_delete(&s.ctx, std::slice::from_ref(&deleted)).await?;
+    tokio::time::timeout(Duration::from_millis(500), s.rx.recv()).await?.expect("render");
+
+    // The deletion is the newest entry; this slide's departures push past the cap
+    let vs = s.sm.visible_set().peek();
+    s.sm.on_scroll(vs.items[0].entity().id(), vs.items[9].entity().id(), true);
+    let vs = next_slide(&mut s.rx).await;
+    assert!(vs.error.is_none(), "error={:?}", vs.error);
+
+    // The renderer still reports the deleted id at the top of the viewport
+    let last = vs.items[vs.items.len() - 1].entity().id();
+    s.sm.on_scroll(deleted.entity().id(), last, true);
+    let index = vs.items.iter().position(|item| item.entity().id() == following).expect("neighbour in window");
+    assert_eq!(s.sm.debug_info().peek().first_visible_index, index);
+
+    Ok(())
+}
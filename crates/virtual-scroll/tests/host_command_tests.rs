@@ -0,0 +1,203 @@
+//! jump_to_live, set_viewport_height and set_predicate tests
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30).
+
+mod common;
+
+use ankurah_signals::Peek;
+use ankurah_virtual_scroll::{ScrollError, ScrollMode};
+use common::*;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+type Renders = mpsc::UnboundedReceiver<VisibleSet<TestMessageView>>;
+
+type Setup = (ankurah::Context, ScrollManager<TestMessageView>, Renders, ankurah_signals::SubscriptionGuard);
+
+async fn setup(count: i64) -> Result<Setup, anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..count).map(|i| (1000 + i, 50))).await?;
+
+    let sm = ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?;
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let guard = sm.visible_set().subscribe(tx);
+    sm.start().await;
+    while rx.try_recv().is_ok() {}
+    Ok((ctx, sm, rx, guard))
+}
+
+/// Wait for the in-flight slide to land
+async fn settle(sm: &ScrollManager<TestMessageView>, rx: &mut Renders) -> VisibleSet<TestMessageView> {
+    loop {
+        let vs = tokio::time::timeout(Duration::from_millis(500), rx.recv())
+            .await
+            .expect("result did not land within 500ms")
+            .expect("channel closed");
+        if !sm.debug_info().peek().update_pending {
+            return vs;
+        }
+    }
+}
+
+/// jump_to_live from mid-history reloads the live window and re-enters Live mode.
+#[tokio::test]
+async fn test_jump_to_live() -> Result<(), anyhow::Error> {
+    let (_ctx, sm, mut rx, _guard) = setup(100).await?;
+
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    let vs = settle(&sm, &mut rx).await;
+    assert_eq!(sm.mode(), ScrollMode::Backward);
+    assert_eq!(timestamps(&vs), (1050..=1099).collect::<Vec<_>>());
+
+    sm.jump_to_live().await?;
+    let vs = sm.visible_set().peek();
+    assert_eq!(sm.mode(), ScrollMode::Live);
+    assert_eq!(timestamps(&vs), (1070..=1099).collect::<Vec<_>>());
+    assert!(vs.has_more_preceding && !vs.has_more_following);
+    assert!(vs.should_auto_scroll);
+    assert!(vs.intersection.is_none());
+
+    // Already live: nothing to do
+    while rx.try_recv().is_ok() {}
+    sm.jump_to_live().await?;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(rx.try_recv().is_err());
+
+    Ok(())
+}
+
+/// jump_to_live while a slide is in flight supersedes it: the slide's result is never
+/// applied and the live window lands instead.
+#[tokio::test]
+async fn test_jump_to_live_supersedes_pending_slide() -> Result<(), anyhow::Error> {
+    let (_ctx, sm, mut rx, _guard) = setup(100).await?;
+
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    assert!(sm.debug_info().peek().update_pending);
+    sm.jump_to_live().await?;
+
+    let vs = sm.visible_set().peek();
+    assert_eq!(sm.mode(), ScrollMode::Live);
+    assert_eq!(timestamps(&vs), (1070..=1099).collect::<Vec<_>>());
+    assert!(vs.intersection.is_none());
+    let debug_info = sm.debug_info().peek();
+    assert!(!debug_info.update_pending);
+    assert_eq!(debug_info.update_count, 2);
+
+    // Nothing from the backward slide lands afterwards
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    while let Ok(vs) = rx.try_recv() {
+        assert!(vs.intersection.is_none(), "superseded slide result applied");
+    }
+    assert_eq!(timestamps(&sm.visible_set().peek()), (1070..=1099).collect::<Vec<_>>());
+    assert_eq!(sm.mode(), ScrollMode::Live);
+
+    Ok(())
+}
+
+/// Host commands require a started, open manager.
+#[tokio::test]
+async fn test_commands_before_start_and_after_close() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?;
+    assert!(matches!(sm.jump_to_live().await, Err(ScrollError::InvalidState(_))));
+    assert!(matches!(sm.set_viewport_height(800).await, Err(ScrollError::InvalidState(_))));
+    assert!(matches!(sm.set_predicate("timestamp > 1010").await, Err(ScrollError::InvalidState(_))));
+
+    sm.start().await;
+    sm.close();
+    assert_eq!(sm.jump_to_live().await, Err(ScrollError::Closed));
+    assert_eq!(sm.set_predicate("timestamp > 1010").await, Err(ScrollError::Closed));
+
+    Ok(())
+}
+
+/// Resizing in Live mode reloads the live window at the new size, in both directions.
+#[tokio::test]
+async fn test_set_viewport_height_live() -> Result<(), anyhow::Error> {
+    let (_ctx, sm, _rx, _guard) = setup(100).await?;
+
+    // 1000px: S = 20, live window = 60
+    sm.set_viewport_height(1000).await?;
+    let vs = sm.visible_set().peek();
+    assert_eq!(timestamps(&vs), (1040..=1099).collect::<Vec<_>>());
+    assert_eq!(sm.debug_info().peek().trigger_threshold, 20);
+    assert_eq!(sm.mode(), ScrollMode::Live);
+
+    // 250px: S = 5, live window = 15
+    sm.set_viewport_height(250).await?;
+    let vs = sm.visible_set().peek();
+    assert_eq!(timestamps(&vs), (1085..=1099).collect::<Vec<_>>());
+    assert!(vs.has_more_preceding);
+
+    Ok(())
+}
+
+/// Resizing mid-history keeps the window; the next slide is sized for the new viewport.
+#[tokio::test]
+async fn test_set_viewport_height_mid_history() -> Result<(), anyhow::Error> {
+    let (_ctx, sm, mut rx, _guard) = setup(200).await?;
+
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    settle(&sm, &mut rx).await;
+    let before = sm.visible_set().peek();
+
+    sm.set_viewport_height(1000).await?;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!sm.debug_info().peek().update_pending);
+    assert_eq!(timestamps(&sm.visible_set().peek()), timestamps(&before));
+
+    // Window 1150-1199, visible 1150-1169 (20 rows): B = 40, so the slide reaches 1199
+    // and fetches 20 + 2 * 40 items
+    sm.on_scroll(before.items[0].entity().id(), before.items[19].entity().id(), true);
+    let vs = settle(&sm, &mut rx).await;
+    assert_eq!(timestamps(&vs), (1100..=1199).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+/// set_predicate replaces the window with the new filter's live window.
+#[tokio::test]
+async fn test_set_predicate() -> Result<(), anyhow::Error> {
+    let (_ctx, sm, mut rx, _guard) = setup(100).await?;
+
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    settle(&sm, &mut rx).await;
+
+    sm.set_predicate("timestamp < 1040").await?;
+    let vs = sm.visible_set().peek();
+    assert_eq!(sm.mode(), ScrollMode::Live);
+    assert_eq!(timestamps(&vs), (1010..=1039).collect::<Vec<_>>());
+    assert!(vs.has_more_preceding && !vs.has_more_following);
+    assert!(sm.current_selection().contains("1040"));
+
+    // Later slides keep the new filter
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    let vs = settle(&sm, &mut rx).await;
+    assert_eq!(timestamps(&vs), (1000..=1039).collect::<Vec<_>>());
+    assert!(!vs.has_more_preceding);
+
+    assert!(matches!(sm.set_predicate("timestamp <").await, Err(ScrollError::QueryFailed(_))));
+
+    Ok(())
+}
+
+/// A new filter matching exactly the current window still lands (no livequery notification).
+#[tokio::test]
+async fn test_set_predicate_same_window() -> Result<(), anyhow::Error> {
+    let (_ctx, sm, _rx, _guard) = setup(100).await?;
+
+    sm.set_predicate("timestamp >= 1070").await?;
+    let vs = sm.visible_set().peek();
+    assert_eq!(timestamps(&vs), (1070..=1099).collect::<Vec<_>>());
+    assert!(!sm.debug_info().peek().update_pending);
+    assert!(!vs.loading_following);
+
+    Ok(())
+}
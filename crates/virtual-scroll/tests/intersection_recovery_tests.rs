@@ -0,0 +1,210 @@
+//! Intersection failure recovery tests
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30).
+//! The anchor of an in-flight backward slide is re-sorted before the result lands, so the
+//! result no longer contains it; the configured `RecoveryStrategy` decides what is emitted.
+
+mod common;
+
+use ankurah_signals::Peek;
+use ankurah_virtual_scroll::{
+    IntersectionFailure, LoadDirection, RecoveryAction, RecoveryStrategy, ScrollConfig, ScrollError, ScrollMode,
+};
+use common::*;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+type Renders = mpsc::UnboundedReceiver<VisibleSet<TestMessageView>>;
+
+struct Setup {
+    ctx: ankurah::Context,
+    sm: Arc<ScrollManager<TestMessageView>>,
+    rx: Renders,
+    _guard: ankurah_signals::SubscriptionGuard,
+}
+
+async fn setup(count: i64, strategy: RecoveryStrategy) -> Result<Setup, anyhow::Error> {
+    setup_at(count, strategy, None).await
+}
+
+async fn setup_at(count: i64, strategy: RecoveryStrategy, start_at: Option<i64>) -> Result<Setup, anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..count).map(|i| (1000 + i, 50))).await?;
+
+    let mut builder = ScrollManager::<TestMessageView>::builder(&ctx)
+        .order_by("timestamp DESC")
+        .viewport(500)
+        .row_height(50)
+        .config(ScrollConfig::default().intersection_recovery(strategy));
+    if let Some(start_at) = start_at {
+        builder = builder.start_at(start_at);
+    }
+    let sm = Arc::new(builder.build()?);
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set().subscribe(tx);
+    sm.start().await;
+    // Skip the initial render (anchored when starting mid-history)
+    while rx.try_recv().is_ok() {}
+    Ok(Setup { ctx, sm, rx, _guard })
+}
+
+/// Trigger a backward slide anchored on the 20th item of the window (visible items 10-19),
+/// then move the anchor to `timestamp` while the slide is in flight. Returns the anchor's id.
+async fn fail_backward_slide(s: &Setup, timestamp: i64) -> Result<EntityId, anyhow::Error> {
+    let vs = s.sm.visible_set().peek();
+    let (first, anchor) = (vs.items[10].clone(), vs.items[19].clone());
+    s.sm.on_scroll(first.entity().id(), anchor.entity().id(), true);
+    set_timestamp(&s.ctx, &anchor, timestamp).await?;
+    Ok(anchor.entity().id())
+}
+
+/// Wait for the render that completes the slide (carries an intersection or a failure;
+/// loading emissions repeat the previous window's)
+async fn next_landed(rx: &mut Renders) -> VisibleSet<TestMessageView> {
+    loop {
+        let vs = tokio::time::timeout(Duration::from_millis(500), rx.recv())
+            .await
+            .expect("slide did not land within 500ms")
+            .expect("channel closed");
+        let loading = vs.loading_preceding || vs.loading_following;
+        if !loading && (vs.intersection.is_some() || vs.intersection_failure.is_some()) {
+            return vs;
+        }
+    }
+}
+
+fn ts_of(vs: &VisibleSet<TestMessageView>, id: EntityId) -> i64 {
+    let index = vs.items.iter().position(|item| item.entity().id() == id).expect("id in window");
+    timestamps(vs)[index]
+}
+
+/// Default: re-anchor on the remaining item closest to the anchor's old sort key.
+#[tokio::test]
+async fn test_nearest_neighbor() -> Result<(), anyhow::Error> {
+    let mut s = setup(100, RecoveryStrategy::NearestNeighbor).await?;
+    let anchor = fail_backward_slide(&s, 1).await?;
+
+    // ts <= 1099 LIMIT 51 without 1089: 1048-1099 less 1089, sentinel 1048 trimmed
+    let vs = next_landed(&mut s.rx).await;
+    assert_eq!(vs.items.len(), 50);
+    assert!(vs.error.is_none());
+    let intersection = vs.intersection.clone().expect("re-anchored");
+    assert_eq!(ts_of(&vs, intersection.entity_id), 1088);
+    assert_eq!(timestamps(&vs)[intersection.index], 1088);
+    assert_eq!(
+        vs.intersection_failure,
+        Some(IntersectionFailure {
+            anchor,
+            direction: LoadDirection::Backward,
+            recovery: RecoveryAction::NearestNeighbor(intersection.entity_id),
+        })
+    );
+    assert_eq!(s.sm.mode(), ScrollMode::Backward);
+
+    Ok(())
+}
+
+/// Report: the result is emitted without an intersection and the failure is reported.
+#[tokio::test]
+async fn test_report() -> Result<(), anyhow::Error> {
+    let mut s = setup(100, RecoveryStrategy::Report).await?;
+    let anchor = fail_backward_slide(&s, 1).await?;
+
+    let vs = next_landed(&mut s.rx).await;
+    assert!(vs.intersection.is_none());
+    assert_eq!(vs.error, Some(ScrollError::IntersectionFailed { anchor, direction: LoadDirection::Backward }));
+    let failure = vs.intersection_failure.expect("failure reported");
+    assert_eq!((failure.anchor, failure.recovery), (anchor, RecoveryAction::None));
+
+    Ok(())
+}
+
+/// RetryWider: the doubled window reaches the moved anchor; its old neighbour holds the position.
+#[tokio::test]
+async fn test_retry_wider() -> Result<(), anyhow::Error> {
+    let mut s = setup(100, RecoveryStrategy::RetryWider).await?;
+    let anchor = fail_backward_slide(&s, 1045).await?;
+
+    // Retry: ts <= 1099 LIMIT 101 → all 100 items, anchor found at its new position
+    let vs = next_landed(&mut s.rx).await;
+    assert_eq!(
+        s.sm.current_selection(),
+        "TRUE AND \"timestamp\" <= 1099 ORDER BY timestamp DESC LIMIT 101"
+    );
+    assert_eq!(vs.items.len(), 100);
+    let intersection = vs.intersection.clone().expect("anchored on retry");
+    assert_eq!(ts_of(&vs, anchor), 1045);
+    assert_eq!(ts_of(&vs, intersection.entity_id), 1088);
+    assert_eq!(timestamps(&vs)[intersection.index], 1088);
+    assert_eq!(vs.intersection_failure.map(|f| f.recovery), Some(RecoveryAction::RetriedWider));
+    assert!(!vs.has_more_preceding);
+    assert_eq!(s.sm.debug_info().peek().update_count, 2);
+
+    Ok(())
+}
+
+/// RetryWider falls back to the nearest neighbour when the retry also misses the anchor.
+#[tokio::test]
+async fn test_retry_wider_falls_back_to_nearest_neighbor() -> Result<(), anyhow::Error> {
+    let mut s = setup(300, RecoveryStrategy::RetryWider).await?;
+    let vs = s.sm.visible_set().peek();
+    assert_eq!(timestamps(&vs)[19], 1289);
+    let anchor = fail_backward_slide(&s, 1).await?;
+
+    // Retry: ts <= 1299 LIMIT 101 → 1198-1299 less 1289; still no anchor
+    let vs = next_landed(&mut s.rx).await;
+    assert_eq!(vs.items.len(), 100);
+    let intersection = vs.intersection.clone().expect("re-anchored");
+    assert_eq!(ts_of(&vs, intersection.entity_id), 1288);
+    let failure = vs.intersection_failure.expect("failure reported");
+    assert_eq!(failure.anchor, anchor);
+    assert_eq!(failure.recovery, RecoveryAction::NearestNeighbor(intersection.entity_id));
+
+    Ok(())
+}
+
+/// JumpToLive: the failed result is dropped and the live window reloaded.
+#[tokio::test]
+async fn test_jump_to_live() -> Result<(), anyhow::Error> {
+    let mut s = setup(100, RecoveryStrategy::JumpToLive).await?;
+    let anchor = fail_backward_slide(&s, 1).await?;
+
+    // Newest 30 without 1089
+    let vs = next_landed(&mut s.rx).await;
+    let expected: Vec<i64> = (1069..=1099).filter(|ts| *ts != 1089).collect();
+    assert_eq!(timestamps(&vs), expected);
+    assert!(vs.intersection.is_none());
+    assert!(vs.error.is_none());
+    assert_eq!(
+        vs.intersection_failure.map(|f| (f.anchor, f.recovery)),
+        Some((anchor, RecoveryAction::JumpedToLive))
+    );
+    assert!((vs.has_more_preceding, vs.has_more_following, vs.should_auto_scroll) == (true, false, true));
+    assert_eq!(s.sm.mode(), ScrollMode::Live);
+    assert_eq!(s.sm.current_selection(), "TRUE ORDER BY timestamp DESC LIMIT 31");
+
+    Ok(())
+}
+
+/// JumpToLive from mid-history: the live window is fetched by a new query.
+#[tokio::test]
+async fn test_jump_to_live_from_history() -> Result<(), anyhow::Error> {
+    let mut s = setup_at(200, RecoveryStrategy::JumpToLive, Some(1149)).await?;
+    assert_eq!(timestamps(&s.sm.visible_set().peek()), (1120..=1149).collect::<Vec<_>>());
+    let anchor = fail_backward_slide(&s, 1).await?;
+
+    // ts <= 1149 LIMIT 51 misses the anchor; the newest 30 are then loaded
+    let vs = next_landed(&mut s.rx).await;
+    assert_eq!(timestamps(&vs), (1170..=1199).collect::<Vec<_>>());
+    assert!(vs.intersection.is_none());
+    assert_eq!(
+        vs.intersection_failure.map(|f| (f.anchor, f.recovery)),
+        Some((anchor, RecoveryAction::JumpedToLive))
+    );
+    assert!((vs.has_more_preceding, vs.has_more_following, vs.should_auto_scroll) == (true, false, true));
+    assert_eq!(s.sm.mode(), ScrollMode::Live);
+    assert_eq!(s.sm.debug_info().peek().update_count, 2);
+
+    Ok(())
+}
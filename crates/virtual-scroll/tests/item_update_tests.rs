@@ -0,0 +1,104 @@
+//! In-place item update tests
+//!
+//! Edits that don't change window membership (message edited, reaction added) should be
+//! reported via `VisibleSet::updated` without disturbing the intersection, mode or flags.
+
+mod common;
+
+use ankurah_signals::Peek;
+use ankurah_virtual_scroll::ScrollMode;
+use common::*;
+use std::sync::Arc;
+
+/// Editing an item in Live mode re-emits the same window with the edited id in `updated`.
+#[tokio::test]
+async fn test_edit_in_live_mode() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(
+        &ctx,
+        "true",
+        "timestamp DESC",
+        50,
+        2.0,
+        500,
+    )?);
+
+    let mut r = MockRenderer::new(sm.clone(), 500);
+    tokio::spawn({
+        let sm = sm.clone();
+        async move { sm.start().await }
+    });
+
+    let vs = r.next_render().await?;
+    r.assert(&vs, 30, 1030..=1059, None, true, false, true, 1050, 1059);
+    assert!(vs.updated.is_empty());
+
+    let edited = vs.items[25].clone();
+    set_height(&ctx, &edited, 80).await?;
+
+    let vs = r.next_render().await?;
+    assert_eq!(timestamps(&vs), (1030..=1059).collect::<Vec<_>>());
+    assert_eq!(vs.updated, vec![edited.entity().id()]);
+    assert!(vs.intersection.is_none(), "edit should not produce an intersection");
+    assert!((vs.has_more_preceding, vs.has_more_following, vs.should_auto_scroll) == (true, false, true));
+    assert_eq!(sm.mode(), ScrollMode::Live);
+
+    Ok(())
+}
+
+/// Editing a visible item after a backward slide keeps Backward mode, flags and window.
+#[tokio::test]
+async fn test_edit_after_backward_slide() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(
+        &ctx,
+        "true",
+        "timestamp DESC",
+        50,
+        2.0,
+        500,
+    )?);
+
+    let mut r = MockRenderer::new(sm.clone(), 500);
+    tokio::spawn({
+        let sm = sm.clone();
+        async move { sm.start().await }
+    });
+
+    let vs = r.next_render().await?;
+    r.assert(&vs, 30, 1030..=1059, None, true, false, true, 1050, 1059);
+
+    r.scroll_up_and_expect(
+        400, 30, 1030..=1059, None,
+        true, false, false,
+        1042, 1051, 600,
+        None,
+    ).await?;
+    let vs = r.scroll_up_and_expect(
+        100, 50, 1010..=1059, Some(1049),
+        true, true, false, 1040, 1049, 1500,
+        Some("TRUE AND \"timestamp\" <= 1059 ORDER BY timestamp DESC LIMIT 51"),
+    ).await?;
+    assert_eq!(sm.mode(), ScrollMode::Backward);
+    let selection = sm.current_selection();
+
+    // Edit a visible item (ts 1045)
+    let edited = vs.items[35].clone();
+    set_height(&ctx, &edited, 80).await?;
+
+    let vs = r.next_render().await?;
+    assert_eq!(timestamps(&vs), (1010..=1059).collect::<Vec<_>>());
+    assert_eq!(vs.updated, vec![edited.entity().id()]);
+    assert!(vs.intersection.is_none(), "edit should not re-anchor the viewport");
+    assert!(vs.error.is_none());
+    assert!((vs.has_more_preceding, vs.has_more_following, vs.should_auto_scroll) == (true, true, false));
+    assert_eq!(sm.mode(), ScrollMode::Backward);
+    assert_eq!(sm.current_selection(), selection, "edit should not issue a new query");
+    assert!(!sm.debug_info().peek().update_pending);
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! Visible range, scroll tracking and anchor correction tests
+
+use ankurah::EntityId;
+use ankurah_virtual_scroll::layout::{stack, visible_range, ItemRect, LayoutSnapshot, ScrollSample, ScrollTracker};
+
+/// `count` rows of `height` px, stacked from the top
+fn rows(count: usize, height: f64) -> Vec<ItemRect> {
+    (0..count).map(|i| ItemRect { top: i as f64 * height, height }).collect()
+}
+
+#[test]
+fn test_visible_range() {
+    let items = rows(20, 50.0);
+
+    assert_eq!(visible_range(&items, 0.0, 500.0), Some((0, 9)));
+    // Partially visible rows at either edge count
+    assert_eq!(visible_range(&items, 25.0, 500.0), Some((0, 10)));
+    // A row ending exactly at the top edge doesn't
+    assert_eq!(visible_range(&items, 500.0, 500.0), Some((10, 19)));
+    assert_eq!(visible_range(&items, 900.0, 500.0), Some((18, 19)));
+
+    assert_eq!(visible_range(&items, 1000.0, 500.0), None);
+    assert_eq!(visible_range(&[], 0.0, 500.0), None);
+}
+
+#[test]
+fn test_stack() {
+    assert_eq!(
+        stack([100.0, 30.0, 200.0], 40.0),
+        vec![
+            ItemRect { top: 40.0, height: 100.0 },
+            ItemRect { top: 140.0, height: 30.0 },
+            ItemRect { top: 170.0, height: 200.0 },
+        ]
+    );
+    assert!(stack([], 0.0).is_empty());
+}
+
+#[test]
+fn test_visible_range_variable_heights() {
+    // A 40px spacer above rows of 100, 30 and 200 px
+    let items = vec![
+        ItemRect { top: 40.0, height: 100.0 },
+        ItemRect { top: 140.0, height: 30.0 },
+        ItemRect { top: 170.0, height: 200.0 },
+    ];
+    assert_eq!(visible_range(&items, 0.0, 40.0), None);
+    assert_eq!(visible_range(&items, 0.0, 150.0), Some((0, 1)));
+    assert_eq!(visible_range(&items, 145.0, 10.0), Some((1, 1)));
+}
+
+#[test]
+fn test_scroll_direction_and_velocity() {
+    let mut tracker = ScrollTracker::new();
+
+    // First event: no history, so no speed
+    assert_eq!(tracker.sample(1000.0, 0.0), Some(ScrollSample { scrolling_backward: false, velocity: 0.0 }));
+
+    // Up 200px in 100ms
+    assert_eq!(tracker.sample(800.0, 100.0), Some(ScrollSample { scrolling_backward: true, velocity: 2000.0 }));
+
+    // Unmoved: direction kept
+    assert_eq!(tracker.sample(800.0, 150.0), Some(ScrollSample { scrolling_backward: true, velocity: 0.0 }));
+
+    // Down 50px in 50ms
+    assert_eq!(tracker.sample(850.0, 200.0), Some(ScrollSample { scrolling_backward: false, velocity: 1000.0 }));
+
+    // Same timestamp: no speed
+    let sample = tracker.sample(800.0, 200.0).unwrap();
+    assert!(sample.scrolling_backward);
+    assert_eq!(sample.velocity, 0.0);
+}
+
+/// Scroll events caused by an adjustment aren't reported, and the adjusted position is
+/// the baseline for the next user event.
+#[test]
+fn test_adjustments_are_not_reported() {
+    let mut tracker = ScrollTracker::new();
+    tracker.sample(500.0, 0.0);
+
+    // Anchor correction after 10 rows were inserted above
+    tracker.adjusted(1000.0);
+    assert_eq!(tracker.sample(1000.0, 10.0), None);
+
+    // The user keeps scrolling up from the corrected position
+    let sample = tracker.sample(990.0, 20.0).unwrap();
+    assert!(sample.scrolling_backward);
+    assert_eq!(sample.velocity, 1000.0);
+
+    // An adjustment the browser rounded is still recognized
+    tracker.adjusted(400.5);
+    assert_eq!(tracker.sample(400.0, 30.0), None);
+
+    // An adjustment followed by a different position (the user got there first) is reported
+    tracker.adjusted(300.0);
+    assert!(tracker.sample(600.0, 40.0).is_some());
+}
+
+/// An adjustment before any scroll event (the initial scroll to the bottom) sets the
+/// direction of the first user scroll.
+#[test]
+fn test_adjustment_before_first_event() {
+    let mut tracker = ScrollTracker::new();
+    tracker.adjusted(1040.0);
+    assert_eq!(tracker.sample(900.0, 0.0), Some(ScrollSample { scrolling_backward: true, velocity: 0.0 }));
+}
+
+#[test]
+fn test_anchored_scroll_top() {
+    let a = EntityId::new();
+    let b = EntityId::new();
+    let snapshot = LayoutSnapshot::from_items([(a, 0.0), (b, 50.0)]);
+    assert_eq!(snapshot.top(&b), Some(50.0));
+
+    // 10 rows of 50px inserted above b, which was 30px below the viewport top
+    assert_eq!(snapshot.anchored_scroll_top(&b, 550.0, 20.0), Some(520.0));
+
+    // Rows removed above b (a forward slide)
+    let snapshot = LayoutSnapshot::from_items([(b, 2000.0)]);
+    assert_eq!(snapshot.anchored_scroll_top(&b, 500.0, 1900.0), Some(400.0));
+
+    // Never above the content
+    assert_eq!(snapshot.anchored_scroll_top(&b, 0.0, 100.0), Some(0.0));
+
+    // Unknown anchor
+    assert_eq!(LayoutSnapshot::new().anchored_scroll_top(&a, 100.0, 0.0), None);
+}
@@ -0,0 +1,98 @@
+//! Start timeout and close tests
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30).
+
+mod common;
+
+use ankurah_signals::Peek;
+use ankurah_virtual_scroll::ScrollError;
+use common::*;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// start_with_timeout behaves like start() when the initial result arrives in time.
+#[tokio::test]
+async fn test_start_with_timeout() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?;
+    sm.start_with_timeout(Duration::from_secs(5)).await?;
+
+    let vs = sm.visible_set().peek();
+    assert!(!vs.initial_loading);
+    assert_eq!(timestamps(&vs), (1030..=1059).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+/// Closing the manager cancels a start() still waiting for the initial result.
+#[tokio::test]
+async fn test_close_cancels_start() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?;
+    let (result, _) = tokio::join!(sm.start_with_timeout(Duration::from_secs(5)), async { sm.close() });
+
+    assert_eq!(result, Err(ScrollError::Closed));
+    assert!(sm.is_closed());
+    let vs = sm.visible_set().peek();
+    assert!(vs.initial_loading);
+    assert!(vs.items.is_empty());
+
+    Ok(())
+}
+
+/// After close, livequery changes and scroll events no longer reach the visible set.
+#[tokio::test]
+async fn test_close_stops_updates() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?;
+    sm.start().await;
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set().subscribe(tx);
+    while rx.try_recv().is_ok() {}
+
+    sm.close();
+    sm.close();
+    assert!(sm.is_closed());
+
+    create_messages(&ctx, [(2000, 50)]).await?;
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[0].entity().id(), vs.items[9].entity().id(), true);
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(rx.try_recv().is_err(), "no renders after close");
+    assert_eq!(timestamps(&sm.visible_set().peek()), (1030..=1059).collect::<Vec<_>>());
+    assert!(!sm.debug_info().peek().update_pending);
+
+    Ok(())
+}
+
+/// Closing mid-slide drops the in-flight slide; its result is never applied.
+#[tokio::test]
+async fn test_close_cancels_pending_slide() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..100).map(|i| (1000 + i, 50))).await?;
+
+    let sm = ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?;
+    sm.start().await;
+
+    // Visible 1070-1079: past the trigger threshold, so a backward slide is issued
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[0].entity().id(), vs.items[9].entity().id(), true);
+    assert!(sm.debug_info().peek().update_pending);
+
+    sm.close();
+    assert!(!sm.debug_info().peek().update_pending);
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let vs = sm.visible_set().peek();
+    assert_eq!(vs.items.len(), 30);
+    assert!(vs.intersection.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,163 @@
+//! Loading state tests
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30).
+
+mod common;
+
+use ankurah_signals::Peek;
+use common::*;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+type Renders = mpsc::UnboundedReceiver<VisibleSet<TestMessageView>>;
+
+async fn next_render(rx: &mut Renders) -> VisibleSet<TestMessageView> {
+    tokio::time::timeout(Duration::from_millis(500), rx.recv()).await.expect("render within 500ms").expect("channel closed")
+}
+
+/// The visible set reports initial loading until start() has produced the first window.
+#[tokio::test]
+async fn test_initial_loading() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?;
+    let vs = sm.visible_set().peek();
+    assert!(vs.initial_loading);
+    assert!(vs.items.is_empty());
+
+    sm.start().await;
+    let vs = sm.visible_set().peek();
+    assert!(!vs.initial_loading);
+    assert_eq!(vs.items.len(), 30);
+    assert!(!vs.loading_preceding && !vs.loading_following);
+
+    Ok(())
+}
+
+/// A backward slide shows a spinner at the leading edge until its result lands.
+#[tokio::test]
+async fn test_loading_preceding() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..100).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?);
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set().subscribe(tx);
+    sm.start().await;
+    while rx.try_recv().is_ok() {}
+
+    // Visible 1080-1089: exits Live mode, then triggers
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+
+    let mut render = next_render(&mut rx).await;
+    while !render.loading_preceding {
+        render = next_render(&mut rx).await;
+    }
+    assert!(!render.loading_following);
+    assert_eq!(timestamps(&render), (1070..=1099).collect::<Vec<_>>());
+    assert!(render.intersection.is_none());
+
+    let landed = next_render(&mut rx).await;
+    assert_eq!(landed.items.len(), 50);
+    assert!(landed.intersection.is_some());
+    assert!(!landed.loading_preceding && !landed.loading_following);
+
+    Ok(())
+}
+
+/// A forward slide from mid-history shows a spinner at the trailing edge.
+#[tokio::test]
+async fn test_loading_following() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..200).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(
+        ScrollManager::<TestMessageView>::builder(&ctx)
+            .order_by("timestamp DESC")
+            .viewport(500)
+            .row_height(50)
+            .start_at(1100)
+            .build()?,
+    );
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set().subscribe(tx);
+    sm.start().await;
+    while rx.try_recv().is_ok() {}
+
+    // Newest screen visible (1091-1100): items_below = 0 → forward TRIGGER
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[20].entity().id(), vs.items[29].entity().id(), false);
+
+    let render = next_render(&mut rx).await;
+    assert!(render.loading_following);
+    assert!(!render.loading_preceding);
+
+    let landed = next_render(&mut rx).await;
+    assert!(landed.intersection.is_some());
+    assert!(!landed.loading_preceding && !landed.loading_following);
+
+    Ok(())
+}
+
+/// A slide issued as soon as the previous one lands (a queued scroll replayed) keeps the
+/// landed intersection on its loading emission, so a renderer reading only the latest
+/// value still anchors the landed window.
+#[tokio::test]
+async fn test_loading_keeps_intersection() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..100).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?);
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set().subscribe(tx);
+    sm.start().await;
+    while rx.try_recv().is_ok() {}
+
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    // Fast fling queued while the first slide is in flight
+    sm.on_scroll_with_velocity(vs.items[0].entity().id(), vs.items[9].entity().id(), true, 1000.0);
+
+    let mut landed = next_render(&mut rx).await;
+    while landed.intersection.is_none() || landed.loading_preceding {
+        landed = next_render(&mut rx).await;
+    }
+    let reissued = next_render(&mut rx).await;
+    assert!(reissued.loading_preceding);
+    assert_eq!(timestamps(&reissued), timestamps(&landed));
+    assert_eq!(reissued.intersection.map(|i| i.entity_id), landed.intersection.map(|i| i.entity_id));
+
+    Ok(())
+}
+
+/// A slide whose result doesn't change the window (nothing older exists) still lands and
+/// clears the spinner.
+#[tokio::test]
+async fn test_loading_cleared_without_new_items() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..30).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?);
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set().subscribe(tx);
+    sm.start().await;
+    while rx.try_recv().is_ok() {}
+
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+
+    let mut render = next_render(&mut rx).await;
+    while !render.loading_preceding {
+        render = next_render(&mut rx).await;
+    }
+    let landed = next_render(&mut rx).await;
+    assert!(!landed.loading_preceding);
+    assert!(!landed.has_more_preceding);
+    assert_eq!(timestamps(&landed), (1000..=1029).collect::<Vec<_>>());
+    assert!(!sm.debug_info().peek().update_pending);
+
+    Ok(())
+}
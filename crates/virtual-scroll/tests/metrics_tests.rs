@@ -0,0 +1,160 @@
+//! Metrics hook tests
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30).
+
+mod common;
+
+use ankurah_signals::Peek;
+use ankurah_virtual_scroll::{
+    IntersectionFailure, LoadDirection, RecoveryAction, ScrollConfig, ScrollMetrics, ScrollMode, SlideMetrics,
+};
+use common::*;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Event {
+    Issued(LoadDirection, usize),
+    Completed(SlideMetrics),
+    Debounced(LoadDirection, usize, usize),
+    Failed(IntersectionFailure),
+    Mode(ScrollMode, ScrollMode),
+}
+
+#[derive(Default)]
+struct Recorder(Mutex<Vec<Event>>);
+
+impl Recorder {
+    fn take(&self) -> Vec<Event> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+impl ScrollMetrics for Recorder {
+    fn slide_issued(&self, direction: LoadDirection, limit: usize) {
+        self.0.lock().unwrap().push(Event::Issued(direction, limit));
+    }
+    fn slide_completed(&self, slide: &SlideMetrics) {
+        self.0.lock().unwrap().push(Event::Completed(slide.clone()));
+    }
+    fn trigger_debounced(&self, direction: LoadDirection, distance: usize, threshold: usize) {
+        self.0.lock().unwrap().push(Event::Debounced(direction, distance, threshold));
+    }
+    fn intersection_failed(&self, failure: &IntersectionFailure) {
+        self.0.lock().unwrap().push(Event::Failed(failure.clone()));
+    }
+    fn mode_changed(&self, from: ScrollMode, to: ScrollMode) {
+        self.0.lock().unwrap().push(Event::Mode(from, to));
+    }
+}
+
+type Renders = mpsc::UnboundedReceiver<VisibleSet<TestMessageView>>;
+
+struct Setup {
+    ctx: ankurah::Context,
+    sm: ScrollManager<TestMessageView>,
+    metrics: Arc<Recorder>,
+    rx: Renders,
+    _guard: ankurah_signals::SubscriptionGuard,
+}
+
+async fn setup(config: ScrollConfig) -> Result<Setup, anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..100).map(|i| (1000 + i, 50))).await?;
+
+    let metrics = Arc::new(Recorder::default());
+    let sm = ScrollManager::<TestMessageView>::builder(&ctx)
+        .order_by("timestamp DESC")
+        .viewport(500)
+        .row_height(50)
+        .config(config)
+        .metrics(metrics.clone())
+        .build()?;
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set().subscribe(tx);
+    sm.start().await;
+    while rx.try_recv().is_ok() {}
+    Ok(Setup { ctx, sm, metrics, rx, _guard })
+}
+
+async fn next_landed(rx: &mut Renders) -> VisibleSet<TestMessageView> {
+    loop {
+        let vs = tokio::time::timeout(Duration::from_millis(500), rx.recv())
+            .await
+            .expect("slide did not land within 500ms")
+            .expect("channel closed");
+        let loading = vs.loading_preceding || vs.loading_following;
+        if !loading && (vs.intersection.is_some() || vs.intersection_failure.is_some()) {
+            return vs;
+        }
+    }
+}
+
+/// A backward slide reports the mode change, its issue and its completion.
+#[tokio::test]
+async fn test_slide_metrics() -> Result<(), anyhow::Error> {
+    let mut s = setup(ScrollConfig::default()).await?;
+    assert!(s.metrics.take().is_empty());
+
+    // Visible 1080-1089: exits Live mode, then triggers
+    let vs = s.sm.visible_set().peek();
+    s.sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    next_landed(&mut s.rx).await;
+
+    let events = s.metrics.take();
+    assert_eq!(
+        events[..2],
+        [Event::Mode(ScrollMode::Live, ScrollMode::Backward), Event::Issued(LoadDirection::Backward, 50)]
+    );
+    let Event::Completed(slide) = &events[2] else { panic!("expected completion, got {:?}", events) };
+    assert_eq!(slide.direction, LoadDirection::Backward);
+    assert_eq!(slide.items_fetched, 51);
+    assert!(!slide.to_live && !slide.recovery);
+    assert!(slide.latency > Duration::ZERO);
+    assert_eq!(events.len(), 3);
+
+    Ok(())
+}
+
+/// A trigger suppressed by the debounce distance is reported instead of issuing a slide.
+#[tokio::test]
+async fn test_debounced_trigger() -> Result<(), anyhow::Error> {
+    let mut s = setup(ScrollConfig::default().debounce_items(100)).await?;
+
+    let vs = s.sm.visible_set().peek();
+    s.sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    let vs = next_landed(&mut s.rx).await;
+    s.metrics.take();
+
+    // Top of the 1050-1099 window: 1080 (last trigger's oldest visible) is 30 items away
+    s.sm.on_scroll(vs.items[0].entity().id(), vs.items[9].entity().id(), true);
+    assert_eq!(s.metrics.take(), vec![Event::Debounced(LoadDirection::Backward, 30, 100)]);
+
+    Ok(())
+}
+
+/// An intersection failure is reported once, with the recovery that was applied.
+#[tokio::test]
+async fn test_intersection_failure() -> Result<(), anyhow::Error> {
+    let mut s = setup(ScrollConfig::default()).await?;
+
+    let vs = s.sm.visible_set().peek();
+    let anchor = vs.items[19].clone();
+    s.sm.on_scroll(vs.items[10].entity().id(), anchor.entity().id(), true);
+    set_timestamp(&s.ctx, &anchor, 1).await?;
+    let vs = next_landed(&mut s.rx).await;
+
+    let intersection = vs.intersection.expect("re-anchored");
+    let failures: Vec<Event> = s.metrics.take().into_iter().filter(|event| matches!(event, Event::Failed(_))).collect();
+    assert_eq!(
+        failures,
+        vec![Event::Failed(IntersectionFailure {
+            anchor: anchor.entity().id(),
+            direction: LoadDirection::Backward,
+            recovery: RecoveryAction::NearestNeighbor(intersection.entity_id),
+        })]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+//! Scroll events received while a slide is in flight
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30).
+//! Scroll events that arrive before the pending slide's result is loaded are coalesced
+//! to the latest one and re-evaluated against the new window.
+
+mod common;
+
+use ankurah_signals::Peek;
+use common::*;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+fn id_at(vs: &VisibleSet<TestMessageView>, ts: i64) -> EntityId {
+    let index = timestamps(vs).iter().position(|t| *t == ts).expect("timestamp in window");
+    vs.items[index].entity().id()
+}
+
+type Renders = mpsc::UnboundedReceiver<VisibleSet<TestMessageView>>;
+
+async fn setup(
+    count: i64,
+) -> Result<(Arc<ScrollManager<TestMessageView>>, Renders, ankurah_signals::SubscriptionGuard), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..count).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(
+        &ctx,
+        "true",
+        "timestamp DESC",
+        50,
+        2.0,
+        500,
+    )?);
+    let (tx, rx) = mpsc::unbounded_channel();
+    let guard = sm.visible_set().subscribe(tx);
+    sm.start().await;
+    Ok((sm, rx, guard))
+}
+
+/// Wait for a render carrying an intersection (a landed slide), skipping mode-change and
+/// loading renders
+async fn next_slide(rx: &mut Renders) -> VisibleSet<TestMessageView> {
+    loop {
+        let vs = tokio::time::timeout(Duration::from_millis(500), rx.recv())
+            .await
+            .expect("slide did not land within 500ms")
+            .expect("channel closed");
+        if vs.intersection.is_some() && !vs.loading_preceding && !vs.loading_following {
+            return vs;
+        }
+    }
+}
+
+/// A second scroll while the first slide is pending does not issue a competing query;
+/// it is re-evaluated once the result lands.
+#[tokio::test]
+async fn test_scroll_during_pending_slide_is_coalesced() -> Result<(), anyhow::Error> {
+    let (sm, mut rx, _guard) = setup(100).await?;
+    let vs = sm.visible_set().peek();
+    assert_eq!(timestamps(&vs), (1070..=1099).collect::<Vec<_>>());
+
+    // visible 1080-1089, items_above=10 → TRIGGER (cursor ts 1099)
+    sm.on_scroll(id_at(&vs, 1080), id_at(&vs, 1089), true);
+    let selection = sm.current_selection();
+    assert_eq!(selection, "TRUE AND \"timestamp\" <= 1099 ORDER BY timestamp DESC LIMIT 51");
+
+    // Keep scrolling before the result arrives: queued, no new query
+    sm.on_scroll(id_at(&vs, 1075), id_at(&vs, 1084), true);
+    sm.on_scroll(id_at(&vs, 1070), id_at(&vs, 1079), true);
+    assert_eq!(sm.current_selection(), selection);
+    assert_eq!(sm.debug_info().peek().update_count, 1);
+
+    // Result lands (1050-1099); the latest intent is replayed against it:
+    // 1070 is now at index 20, items_above=20 → no further slide
+    let vs = next_slide(&mut rx).await;
+    assert_eq!(timestamps(&vs), (1050..=1099).collect::<Vec<_>>());
+    let debug = sm.debug_info().peek();
+    assert_eq!((debug.first_visible_index, debug.last_visible_index), (20, 29));
+    assert_eq!(debug.update_count, 1);
+    assert!(!debug.update_pending);
+
+    Ok(())
+}
+
+/// A queued intent that is past the trigger in the new window starts the next slide
+/// immediately, without waiting for another scroll event.
+#[tokio::test]
+async fn test_queued_intent_triggers_next_slide() -> Result<(), anyhow::Error> {
+    let (sm, mut rx, _guard) = setup(100).await?;
+    let vs = sm.visible_set().peek();
+
+    sm.on_scroll(id_at(&vs, 1080), id_at(&vs, 1089), true);
+
+    // Fast fling queued: 1000 px/s → prefetch 10, trigger at 20 items above
+    sm.on_scroll_with_velocity(id_at(&vs, 1070), id_at(&vs, 1079), true, 1000.0);
+
+    // First slide lands (1050-1099). Replayed intent: 1070 at index 20 <= 20 → TRIGGER
+    // cursor = min(29 + 20, 49) = 49 → ts 1099, limit = 10 + 40 + 10 = 60
+    let vs = next_slide(&mut rx).await;
+    assert_eq!(timestamps(&vs), (1050..=1099).collect::<Vec<_>>());
+    assert_eq!(sm.debug_info().peek().update_count, 2);
+    assert_eq!(
+        sm.current_selection(),
+        "TRUE AND \"timestamp\" <= 1099 ORDER BY timestamp DESC LIMIT 61"
+    );
+
+    let vs = next_slide(&mut rx).await;
+    assert_eq!(timestamps(&vs), (1040..=1099).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+/// Scrolling continuously toward the oldest edge, reporting the top of whatever window is
+/// current without waiting for renders, walks through every window without stalling.
+#[tokio::test]
+async fn test_continuous_scroll_through_many_windows() -> Result<(), anyhow::Error> {
+    let (sm, _rx, _guard) = setup(300).await?;
+
+    for _ in 0..500 {
+        let vs = sm.visible_set().peek();
+        if !vs.has_more_preceding {
+            break;
+        }
+        // Top screen of the window is visible
+        sm.on_scroll(vs.items[0].entity().id(), vs.items[9].entity().id(), true);
+        tokio::time::sleep(Duration::from_millis(1)).await;
+    }
+
+    // Let the final slide land
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let vs = sm.visible_set().peek();
+    assert!(!vs.has_more_preceding, "should reach the oldest edge");
+    assert_eq!(timestamps(&vs)[0], 1000);
+    let debug = sm.debug_info().peek();
+    assert!(!debug.update_pending);
+    // Each slide moves the window 20 items older: 1270 → 1000 takes at least 14 slides
+    assert!(debug.update_count >= 14, "update_count={}", debug.update_count);
+
+    Ok(())
+}
+
+/// Reversing direction mid-flight attributes the in-flight result to the slide that issued
+/// it (no intersection error), then runs the reversed slide against the new window.
+#[tokio::test]
+async fn test_reversal_mid_flight() -> Result<(), anyhow::Error> {
+    let (sm, mut rx, _guard) = setup(100).await?;
+    let vs = sm.visible_set().peek();
+    let (anchor_back, anchor_fwd) = (id_at(&vs, 1089), id_at(&vs, 1090));
+
+    // Backward slide in flight (anchor = newest visible 1089)
+    sm.on_scroll(id_at(&vs, 1080), anchor_back, true);
+    // User reverses to the bottom before it lands
+    sm.on_scroll(anchor_fwd, id_at(&vs, 1099), false);
+
+    // Backward result: 1050-1099, anchored on 1089
+    let vs = next_slide(&mut rx).await;
+    assert_eq!(timestamps(&vs), (1050..=1099).collect::<Vec<_>>());
+    let intersection = vs.intersection.as_ref().unwrap();
+    assert_eq!(intersection.entity_id, anchor_back);
+    assert_eq!(intersection.direction, ankurah_virtual_scroll::LoadDirection::Backward);
+    assert!(vs.error.is_none());
+
+    // Replayed forward intent: 1090-1099 at indices 40-49, items_below=0 → forward slide
+    // cursor = 40 - 20 = 20 → ts 1070; only 30 newer items remain → back to Live
+    let vs = next_slide(&mut rx).await;
+    assert_eq!(timestamps(&vs), (1070..=1099).collect::<Vec<_>>());
+    let intersection = vs.intersection.as_ref().unwrap();
+    assert_eq!(intersection.entity_id, anchor_fwd);
+    assert_eq!(intersection.direction, ankurah_virtual_scroll::LoadDirection::Forward);
+    assert!(vs.error.is_none());
+    assert_eq!(sm.mode(), ankurah_virtual_scroll::ScrollMode::Live);
+
+    Ok(())
+}
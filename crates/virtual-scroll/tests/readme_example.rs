@@ -16,15 +16,14 @@ use common::{TestMessageView, durable_sled_setup};
 async fn scroll_manager_example() -> Result<(), Box<dyn std::error::Error>> {
     let ctx = durable_sled_setup().await?;
 
-    // Create scroll manager with full configuration
-    let scroll_manager = ScrollManager::<TestMessageView>::new(
-        &ctx,
-        "true",              // Filter predicate (e.g., "room = 'general'")
-        "timestamp DESC",    // Display order
-        40,                  // Minimum row height (pixels)
-        2.0,                 // Buffer factor (2.0 = 2x viewport)
-        600,                 // Viewport height (pixels)
-    )?;
+    // Create scroll manager; unset options use defaults
+    let scroll_manager = ScrollManager::<TestMessageView>::builder(&ctx)
+        .predicate("true")           // Filter predicate (e.g., "room = 'general'")
+        .order_by("timestamp DESC")  // Display order
+        .row_height(40)              // Minimum row height (pixels)
+        .buffer(2.0)                 // Buffer factor (2.0 = 2x viewport)
+        .viewport(600)               // Viewport height (pixels)
+        .build()?;
 
     // Initialize (runs initial query)
     scroll_manager.start().await;
@@ -0,0 +1,122 @@
+//! Re-sorted item tests
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30).
+//! Editing an item's timestamp moves it in display order; the livequery keeps it at its
+//! old position, so the manager re-sorts the window itself.
+
+mod common;
+
+use ankurah_signals::Peek;
+use ankurah_virtual_scroll::{LoadDirection, VisibleSetChange};
+use common::*;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+type Renders = mpsc::UnboundedReceiver<VisibleSet<TestMessageView>>;
+
+struct Setup {
+    ctx: ankurah::Context,
+    sm: Arc<ScrollManager<TestMessageView>>,
+    rx: Renders,
+    _guard: ankurah_signals::SubscriptionGuard,
+}
+
+async fn setup(count: i64) -> Result<Setup, anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..count).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?);
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set().subscribe(tx);
+    sm.start().await;
+    while rx.try_recv().is_ok() {}
+    Ok(Setup { ctx, sm, rx, _guard })
+}
+
+fn item_at(vs: &VisibleSet<TestMessageView>, ts: i64) -> TestMessageView {
+    let index = timestamps(vs).iter().position(|t| *t == ts).expect("timestamp in window");
+    vs.items[index].clone()
+}
+
+async fn next_render(rx: &mut Renders) -> VisibleSet<TestMessageView> {
+    tokio::time::timeout(Duration::from_millis(500), rx.recv()).await.expect("render within 500ms").expect("channel closed")
+}
+
+/// An item re-sorted within the window moves to its new position and is reported as moved.
+#[tokio::test]
+async fn test_resorted_within_window() -> Result<(), anyhow::Error> {
+    let mut s = setup(100).await?;
+    let item = item_at(&s.sm.visible_set().peek(), 1075);
+    let id = item.entity().id();
+    let (tx, mut diffs) = mpsc::unbounded_channel();
+    let _diff_guard = s.sm.visible_set_diff().subscribe(tx);
+
+    set_timestamp(&s.ctx, &item, 1500).await?;
+    let vs = next_render(&mut s.rx).await;
+    let expected: Vec<i64> = (1070..=1099).filter(|ts| *ts != 1075).chain([1500]).collect();
+    assert_eq!(timestamps(&vs), expected);
+    assert_eq!(vs.moved, vec![id]);
+
+    let diff = diffs.recv().await.expect("diff");
+    assert!(
+        diff.changes.iter().any(|change| matches!(change, VisibleSetChange::Move { id: moved, to: 29, .. } if *moved == id)),
+        "{:?}",
+        diff.changes
+    );
+
+    Ok(())
+}
+
+/// An item re-sorted past the window's oldest edge leaves the window.
+#[tokio::test]
+async fn test_resorted_out_of_window() -> Result<(), anyhow::Error> {
+    let mut s = setup(100).await?;
+    let item = item_at(&s.sm.visible_set().peek(), 1089);
+
+    set_timestamp(&s.ctx, &item, 1).await?;
+    let vs = next_render(&mut s.rx).await;
+    let expected: Vec<i64> = (1070..=1099).filter(|ts| *ts != 1089).collect();
+    assert_eq!(timestamps(&vs), expected);
+    assert_eq!(vs.moved, vec![item.entity().id()]);
+
+    // It stays out on later emissions, though the livequery still returns it
+    let other = item_at(&vs, 1080);
+    set_timestamp(&s.ctx, &other, 1098).await?;
+    let vs = next_render(&mut s.rx).await;
+    assert!(!timestamps(&vs).contains(&1));
+    assert_eq!(vs.moved, vec![other.entity().id()]);
+    assert!(timestamps(&vs).is_sorted());
+
+    Ok(())
+}
+
+/// An anchor re-sorted within the slide's result is held by its old neighbour, not
+/// followed to its new position.
+#[tokio::test]
+async fn test_anchor_resorted_mid_flight() -> Result<(), anyhow::Error> {
+    let mut s = setup(100).await?;
+    let vs = s.sm.visible_set().peek();
+    let anchor = item_at(&vs, 1089);
+
+    s.sm.on_scroll(item_at(&vs, 1080).entity().id(), anchor.entity().id(), true);
+    set_timestamp(&s.ctx, &anchor, 1060).await?;
+
+    let vs = loop {
+        let vs = next_render(&mut s.rx).await;
+        let loading = vs.loading_preceding || vs.loading_following;
+        if !loading && (vs.intersection.is_some() || vs.intersection_failure.is_some()) {
+            break vs;
+        }
+    };
+    assert!(vs.error.is_none());
+    assert!(vs.intersection_failure.is_none());
+    assert!(timestamps(&vs).is_sorted());
+    let intersection = vs.intersection.clone().expect("anchored");
+    assert_eq!(intersection.entity_id, item_at(&vs, 1088).entity().id());
+    assert_eq!(timestamps(&vs)[intersection.index], 1088);
+    assert_eq!(intersection.direction, LoadDirection::Backward);
+    assert!(timestamps(&vs).contains(&1060));
+
+    Ok(())
+}
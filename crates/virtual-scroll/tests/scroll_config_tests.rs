@@ -0,0 +1,263 @@
+//! ScrollConfig tests
+//!
+//! Uses the standard configuration (60-100 messages, 50px rows, 500px viewport, S = 10)
+//! with non-default trigger, buffer, debounce and window-size settings.
+
+mod common;
+
+use ankurah_signals::Peek;
+use ankurah_virtual_scroll::ScrollConfig;
+use common::*;
+use std::sync::Arc;
+
+async fn start_with_config(
+    ctx: &ankurah::Context,
+    config: ScrollConfig,
+) -> Result<(Arc<ScrollManager<TestMessageView>>, MockRenderer<TestMessageView>), anyhow::Error> {
+    let sm = Arc::new(ScrollManager::<TestMessageView>::with_config(
+        ctx,
+        "true",
+        "timestamp DESC",
+        50,
+        500,
+        config,
+    )?);
+    let r = MockRenderer::new(sm.clone(), 500);
+    tokio::spawn({
+        let sm = sm.clone();
+        async move { sm.start().await }
+    });
+    Ok((sm, r))
+}
+
+/// Configurations that break the windowing invariants are rejected up front.
+#[tokio::test]
+async fn test_invalid_config_rejected() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+
+    let invalid = [
+        ScrollConfig::default().trigger_screens(0.0),
+        ScrollConfig::default().trigger_screens(2.0), // buffer must exceed trigger
+        ScrollConfig::default().debounce_items(0),
+        ScrollConfig::default().max_window_size(29), // live window is 30
+    ];
+    for config in invalid {
+        let result =
+            ScrollManager::<TestMessageView>::with_config(&ctx, "true", "timestamp DESC", 50, 500, config.clone());
+        assert!(result.is_err(), "expected {:?} to be rejected", config);
+    }
+
+    assert!(ScrollManager::<TestMessageView>::with_config(
+        &ctx,
+        "true",
+        "timestamp DESC",
+        50,
+        500,
+        ScrollConfig::default().max_window_size(30),
+    )
+    .is_ok());
+
+    Ok(())
+}
+
+/// A larger trigger threshold paginates earlier: at 14 items above instead of 10.
+#[tokio::test]
+async fn test_earlier_trigger() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let (sm, mut r) = start_with_config(&ctx, ScrollConfig::default().trigger_screens(1.5)).await?;
+    let vs = r.next_render().await?;
+    r.assert(&vs, 30, 1030..=1059, None, true, false, true, 1050, 1059);
+    assert_eq!(sm.debug_info().peek().trigger_threshold, 15);
+
+    // offset 1000→700: visible indices 14-23, items_above=14 <= 15 → TRIGGER
+    // cursor = min(23 + 20, 29) = 29 → ts 1059
+    r.scroll_up_collect(300).await;
+    assert_eq!(
+        sm.current_selection(),
+        "TRUE AND \"timestamp\" <= 1059 ORDER BY timestamp DESC LIMIT 51"
+    );
+    assert_eq!(sm.visible_set().peek().items.len(), 50);
+
+    Ok(())
+}
+
+/// A three-screen buffer widens both the live window and the slide limit.
+#[tokio::test]
+async fn test_wider_buffer() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..100).map(|i| (1000 + i, 50))).await?;
+
+    let (sm, mut r) = start_with_config(&ctx, ScrollConfig::default().buffer_screens(3.0)).await?;
+
+    // Live window = (2 * 1.5 + 1) * 10 = 40
+    let vs = r.next_render().await?;
+    r.assert(&vs, 40, 1060..=1099, None, true, false, true, 1090, 1099);
+
+    // offset 1500→500: visible indices 10-19, items_above=10 → TRIGGER
+    // cursor = min(19 + 30, 39) = 39 → ts 1099, limit = 10 + 2 * 30 = 70
+    r.scroll_up_collect(1000).await;
+    assert_eq!(
+        sm.current_selection(),
+        "TRUE AND \"timestamp\" <= 1099 ORDER BY timestamp DESC LIMIT 71"
+    );
+    let vs = sm.visible_set().peek();
+    assert_eq!(timestamps(&vs), (1030..=1099).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+/// `new()`'s buffer_factor only widens the live window: slides keep B = 2S.
+#[tokio::test]
+async fn test_buffer_factor_keeps_slide_buffer() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..100).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 3.0, 500)?);
+    let mut r = MockRenderer::new(sm.clone(), 500);
+    tokio::spawn({
+        let sm = sm.clone();
+        async move { sm.start().await }
+    });
+
+    // Live window = (2 * 1.5 + 1) * 10 = 40
+    let vs = r.next_render().await?;
+    r.assert(&vs, 40, 1060..=1099, None, true, false, true, 1090, 1099);
+
+    // offset 1500→500: visible indices 10-19, items_above=10 → TRIGGER
+    // cursor = min(19 + 20, 39) = 39 → ts 1099, limit = 10 + 2 * 20 = 50
+    r.scroll_up_collect(1000).await;
+    assert_eq!(
+        sm.current_selection(),
+        "TRUE AND \"timestamp\" <= 1099 ORDER BY timestamp DESC LIMIT 51"
+    );
+    let vs = sm.visible_set().peek();
+    assert_eq!(timestamps(&vs), (1050..=1099).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+/// A longer debounce distance suppresses a trigger that the default would allow.
+#[tokio::test]
+async fn test_longer_debounce() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..100).map(|i| (1000 + i, 50))).await?;
+
+    let (sm, mut r) = start_with_config(&ctx, ScrollConfig::default().debounce_items(100)).await?;
+    let vs = r.next_render().await?;
+    r.assert(&vs, 30, 1070..=1099, None, true, false, true, 1090, 1099);
+
+    // First trigger always passes debounce: 50 items (1050-1099)
+    r.scroll_up_collect(500).await;
+    let first_selection = sm.current_selection();
+    assert_eq!(
+        first_selection,
+        "TRUE AND \"timestamp\" <= 1099 ORDER BY timestamp DESC LIMIT 51"
+    );
+
+    // Reach the top buffer again; only 20 items scrolled since the last trigger (< 100)
+    r.scroll_up_collect(1000).await;
+    assert_eq!(sm.current_selection(), first_selection, "debounced trigger must not slide");
+
+    Ok(())
+}
+
+/// A window cap below the slide limit evicts trailing buffer on the far side.
+#[tokio::test]
+async fn test_max_window_size_evicts_far_side() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let (sm, mut r) = start_with_config(&ctx, ScrollConfig::default().max_window_size(40)).await?;
+    let vs = r.next_render().await?;
+    r.assert(&vs, 30, 1030..=1059, None, true, false, true, 1050, 1059);
+
+    // offset 1000→500: visible 1040-1049 → TRIGGER
+    // Uncapped limit 50 exceeds the cap by 10, so the cursor moves from ts 1059 to 1049
+    r.scroll_up_collect(500).await;
+    assert_eq!(
+        sm.current_selection(),
+        "TRUE AND \"timestamp\" <= 1049 ORDER BY timestamp DESC LIMIT 41"
+    );
+    let vs = sm.visible_set().peek();
+    assert_eq!(timestamps(&vs), (1010..=1049).collect::<Vec<_>>());
+    assert!(vs.has_more_following);
+    assert_eq!(sm.debug_info().peek().evicted_items, 10);
+
+    Ok(())
+}
+
+/// Wait for the render that lands a slide
+async fn next_slide(r: &mut MockRenderer<TestMessageView>) -> Result<VisibleSet<TestMessageView>, anyhow::Error> {
+    loop {
+        let vs = r.next_render().await?;
+        if vs.intersection.is_some() && !vs.loading_preceding && !vs.loading_following {
+            return Ok(vs);
+        }
+    }
+}
+
+/// The cap is hard: a visible span too large to fit evicts visible items on the far side
+/// (newer, for a backward slide) and anchors on the newest item kept.
+#[tokio::test]
+async fn test_max_window_size_caps_large_visible_span() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..200).map(|i| (1000 + i, 50))).await?;
+
+    let (sm, mut r) = start_with_config(&ctx, ScrollConfig::default().max_window_size(40)).await?;
+    let vs = r.next_render().await?;
+    assert_eq!(timestamps(&vs), (1170..=1199).collect::<Vec<_>>());
+
+    // Zoomed out: visible 1180-1199 (indices 10-29), items_above = 10 → TRIGGER
+    // Uncapped limit 20 + 2 * 20 = 60; the cursor moves 19 items from 1199 to 1180
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[29].entity().id(), true);
+    let vs = next_slide(&mut r).await?;
+    assert_eq!(
+        sm.current_selection(),
+        "TRUE AND \"timestamp\" <= 1180 ORDER BY timestamp DESC LIMIT 41"
+    );
+    assert_eq!(timestamps(&vs), (1141..=1180).collect::<Vec<_>>());
+    let intersection = vs.intersection.clone().expect("anchored");
+    assert_eq!(intersection.index, 39);
+    assert!(vs.has_more_preceding && vs.has_more_following);
+    assert_eq!(sm.debug_info().peek().evicted_items, 19);
+
+    Ok(())
+}
+
+/// A capped forward slide evicts older items and reports them as preceding.
+#[tokio::test]
+async fn test_max_window_size_forward_eviction() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..200).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(
+        ScrollManager::<TestMessageView>::builder(&ctx)
+            .order_by("timestamp DESC")
+            .viewport(500)
+            .row_height(50)
+            .start_at(1100)
+            .config(ScrollConfig::default().max_window_size(40))
+            .build()?,
+    );
+    let mut r = MockRenderer::new(sm.clone(), 500);
+    sm.start().await;
+    let vs = r.next_render().await?;
+    assert_eq!(timestamps(&vs), (1071..=1100).collect::<Vec<_>>());
+
+    // Whole window visible, items_below = 0 → forward TRIGGER
+    // Uncapped limit 30 + 2 * 20 = 70; the cursor moves 29 items from 1071 to 1100
+    sm.on_scroll(vs.items[0].entity().id(), vs.items[29].entity().id(), false);
+    let vs = next_slide(&mut r).await?;
+    assert_eq!(
+        sm.current_selection(),
+        "TRUE AND \"timestamp\" >= 1100 ORDER BY timestamp ASC LIMIT 41"
+    );
+    assert_eq!(timestamps(&vs), (1100..=1139).collect::<Vec<_>>());
+    assert_eq!(vs.intersection.clone().expect("anchored").index, 0);
+    assert!(vs.has_more_preceding && vs.has_more_following);
+    assert_eq!(sm.debug_info().peek().evicted_items, 29);
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! ScrollError reporting tests
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, live window = 30).
+
+mod common;
+
+use ankurah_signals::Peek;
+use common::*;
+
+/// Scroll events before start() are ignored: hosts may report scrolls while mounting.
+#[tokio::test]
+async fn test_on_scroll_before_start() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    let ids = create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?;
+    sm.on_scroll(ids[40], ids[49], true);
+
+    let vs = sm.visible_set().peek();
+    assert!(vs.error.is_none(), "error={:?}", vs.error);
+    assert!(vs.items.is_empty());
+    assert!(vs.initial_loading);
+    assert!(!sm.debug_info().peek().update_pending);
+
+    sm.start().await;
+    let vs = sm.visible_set().peek();
+    assert!(vs.error.is_none());
+    assert_eq!(vs.items.len(), 30);
+
+    Ok(())
+}
+
+/// Values without a meaningful order can't be used as a cursor; start_at reports the
+/// conversion failure from build().
+#[tokio::test]
+async fn test_start_at_unorderable_value() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+
+    let result = ScrollManager::<TestMessageView>::builder(&ctx)
+        .order_by("timestamp DESC")
+        .viewport(500)
+        .row_height(50)
+        .start_at(Value::Binary(vec![1, 2, 3]))
+        .build();
+    let message = result.err().expect("binary start_at should be rejected").to_string();
+    assert!(message.contains("Cursor conversion failed"), "{}", message);
+
+    Ok(())
+}
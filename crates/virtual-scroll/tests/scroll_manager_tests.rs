@@ -31,6 +31,7 @@
 
 mod common;
 
+use ankurah_signals::Peek;
 use common::*;
 use std::sync::Arc;
 
@@ -656,13 +657,21 @@ async fn test_exactly_live_window_items() -> Result<(), anyhow::Error> {
     // Scroll up - exits Live mode (items_below = 10 <= screen_items)
     // Mode-change render fires (has_more_preceding=true still, query hasn't completed)
     // Pagination query is triggered but returns same 30 items (no new data)
-    // Since item set unchanged, only the mode-change render fires
     r.scroll_up_and_expect(
         500, 30, 1000..=1029, None,
         true, false, false, 1010, 1019, 500,
         Some("TRUE AND \"timestamp\" <= 1029 ORDER BY timestamp DESC LIMIT 51"),
     ).await?;
 
+    // The unchanged result still lands the slide (after its loading emission): nothing
+    // older exists, and the window stays put
+    let vs = r.next_window_render().await?;
+    assert_eq!(timestamps(&vs), (1000..=1029).collect::<Vec<_>>());
+    assert!(!vs.has_more_preceding);
+    assert_eq!(vs.intersection.map(|i| i.index), Some(19));
+    assert_eq!(r.scroll_offset, 500);
+    assert!(!sm.debug_info().peek().update_pending);
+
     // Continue scrolling to top - no additional renders (already in Backward mode)
     r.up_no_render(500, 1000, 1009).await;
     assert_eq!(r.scroll_offset, 0);
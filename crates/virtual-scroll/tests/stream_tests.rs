@@ -0,0 +1,159 @@
+//! visible_set_stream and next_settled tests
+//!
+//! Standard configuration: 50px rows, 500px viewport (S = 10, B = 20, live window = 30).
+
+mod common;
+
+use ankurah_signals::Peek;
+use common::*;
+use futures::StreamExt;
+use std::sync::atomic::{AtomicBool, Ordering::SeqCst};
+use std::sync::Arc;
+use std::time::Duration;
+
+async fn setup(count: i64) -> Result<(ankurah::Context, ScrollManager<TestMessageView>), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..count).map(|i| (1000 + i, 50))).await?;
+    let sm = ScrollManager::<TestMessageView>::new(&ctx, "true", "timestamp DESC", 50, 2.0, 500)?;
+    Ok((ctx, sm))
+}
+
+/// The stream starts with the current window, then yields the spinner emission and the
+/// slide's result in order.
+#[tokio::test]
+async fn test_visible_set_stream() -> Result<(), anyhow::Error> {
+    let (_ctx, sm) = setup(100).await?;
+    sm.start().await;
+
+    let mut stream = sm.visible_set_stream();
+    let vs = stream.next().await.expect("initial value");
+    assert_eq!(timestamps(&vs), (1070..=1099).collect::<Vec<_>>());
+
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    let mut renders = Vec::new();
+    while let Ok(Some(vs)) = tokio::time::timeout(Duration::from_millis(200), stream.next()).await {
+        renders.push(vs);
+    }
+    assert!(renders.iter().any(|vs| vs.loading_preceding));
+    let last = renders.last().expect("slide result");
+    assert_eq!(timestamps(last), (1050..=1099).collect::<Vec<_>>());
+    assert!(!last.loading_preceding);
+
+    Ok(())
+}
+
+/// next_settled waits for the initial window, and then for a triggered slide to land;
+/// when nothing is in flight it resolves straight away.
+#[tokio::test]
+async fn test_next_settled() -> Result<(), anyhow::Error> {
+    let (_ctx, sm) = setup(100).await?;
+
+    let (vs, ()) = tokio::join!(sm.next_settled(), sm.start());
+    assert!(!vs.initial_loading);
+    assert_eq!(timestamps(&vs), (1070..=1099).collect::<Vec<_>>());
+
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    assert!(sm.debug_info().peek().update_pending);
+    let vs = sm.next_settled().await;
+    assert!(!sm.debug_info().peek().update_pending);
+    assert_eq!(timestamps(&vs), (1050..=1099).collect::<Vec<_>>());
+
+    // Scrolling within the buffer doesn't slide
+    let first = vs.items.iter().position(|item| item.timestamp().unwrap() == 1075).unwrap();
+    sm.on_scroll(vs.items[first].entity().id(), vs.items[first + 9].entity().id(), false);
+    let settled = tokio::time::timeout(Duration::from_millis(50), sm.next_settled()).await?;
+    assert_eq!(timestamps(&settled), timestamps(&vs));
+
+    Ok(())
+}
+
+/// A scroll event received during a slide is replayed when it lands; next_settled waits
+/// for the slide that replay triggers too.
+#[tokio::test]
+async fn test_next_settled_waits_for_queued_scroll() -> Result<(), anyhow::Error> {
+    let (_ctx, sm) = setup(100).await?;
+    sm.start().await;
+
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    // Fast fling queued: 1070 is within the trigger (20 items above) of the first result
+    sm.on_scroll_with_velocity(vs.items[0].entity().id(), vs.items[9].entity().id(), true, 1000.0);
+
+    let vs = sm.next_settled().await;
+    assert!(!sm.debug_info().peek().update_pending);
+    assert_eq!(sm.debug_info().peek().update_count, 2);
+    assert_eq!(timestamps(&vs), (1040..=1099).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+/// Closing the manager while a slide is in flight resolves next_settled with the last
+/// window.
+#[tokio::test]
+async fn test_next_settled_on_close() -> Result<(), anyhow::Error> {
+    let (_ctx, sm) = setup(100).await?;
+    sm.start().await;
+
+    let vs = sm.visible_set().peek();
+    sm.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+    let (settled, ()) = tokio::join!(sm.next_settled(), async { sm.close() });
+    assert_eq!(timestamps(&settled), (1070..=1099).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+/// The stream ends once the manager is closed, or dropped.
+#[tokio::test]
+async fn test_visible_set_stream_ends() -> Result<(), anyhow::Error> {
+    let (_ctx, sm) = setup(100).await?;
+    sm.start().await;
+    let mut stream = sm.visible_set_stream();
+    assert!(stream.next().await.is_some());
+    sm.close();
+    assert!(tokio::time::timeout(Duration::from_millis(500), stream.next()).await?.is_none());
+
+    let (_ctx, sm) = setup(100).await?;
+    sm.start().await;
+    let mut stream = sm.visible_set_stream();
+    assert!(stream.next().await.is_some());
+    drop(sm);
+    assert!(tokio::time::timeout(Duration::from_millis(500), stream.next()).await?.is_none());
+
+    Ok(())
+}
+
+/// Emissions in quick succession are yielded once each, in order. The signal hands each
+/// notification its value as of delivery, so when the scroll below emits before the
+/// stream's listener has run for the insert, both notifications carry the later value.
+#[tokio::test]
+async fn test_visible_set_stream_rapid_updates() -> Result<(), anyhow::Error> {
+    let (ctx, sm) = setup(100).await?;
+    let sm = Arc::new(sm);
+    sm.start().await;
+
+    // Subscribed ahead of the stream: slide back as soon as the insert is emitted
+    let scrolled = AtomicBool::new(false);
+    let manager = sm.clone();
+    let guard = sm.visible_set().subscribe(move |vs: VisibleSet<TestMessageView>| {
+        if vs.items.last().and_then(|item| item.timestamp().ok()) == Some(1100) && !scrolled.swap(true, SeqCst) {
+            manager.on_scroll(vs.items[10].entity().id(), vs.items[19].entity().id(), true);
+        }
+    });
+    let mut stream = sm.visible_set_stream();
+
+    create_messages(&ctx, [(1100, 50)]).await?;
+    let mut renders = Vec::new();
+    while let Ok(Some(vs)) = tokio::time::timeout(Duration::from_millis(200), stream.next()).await {
+        renders.push((timestamps(&vs), vs.should_auto_scroll, vs.loading_preceding));
+    }
+    drop(guard);
+
+    let flags: Vec<_> = renders.iter().map(|(_, auto_scroll, loading)| (*auto_scroll, *loading)).collect();
+    assert!(renders.windows(2).all(|pair| pair[0] != pair[1]), "repeated emission: {:?}", flags);
+    assert_eq!(renders.first().unwrap().0, (1070..=1099).collect::<Vec<_>>());
+    assert_eq!(renders.last().unwrap().0, (1051..=1100).collect::<Vec<_>>());
+    assert_eq!(flags.last(), Some(&(false, false)));
+    assert!(flags.contains(&(false, true)), "spinner emission: {:?}", flags);
+
+    Ok(())
+}
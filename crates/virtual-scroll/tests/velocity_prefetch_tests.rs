@@ -0,0 +1,86 @@
+//! Velocity-aware prefetch tests
+//!
+//! Standard configuration: 100 messages (ts 1000-1099), 50px rows, 500px viewport.
+//! - S = 10, B = 20, base trigger = 10 items
+//! - Prefetch = ceil(velocity * 0.5s / 50px), capped at B
+
+mod common;
+
+use ankurah_signals::Peek;
+use common::*;
+use std::sync::Arc;
+
+async fn setup() -> Result<(Arc<ScrollManager<TestMessageView>>, MockRenderer<TestMessageView>), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..100).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(
+        &ctx,
+        "true",
+        "timestamp DESC",
+        50,
+        2.0,
+        500,
+    )?);
+    let mut r = MockRenderer::new(sm.clone(), 500);
+    tokio::spawn({
+        let sm = sm.clone();
+        async move { sm.start().await }
+    });
+    let vs = r.next_render().await?;
+    r.assert(&vs, 30, 1070..=1099, None, true, false, true, 1090, 1099);
+    Ok((sm, r))
+}
+
+const LIVE_SELECTION: &str = "TRUE ORDER BY timestamp DESC LIMIT 30";
+
+/// Without velocity, 14 items above the viewport does not trigger.
+#[tokio::test]
+async fn test_slow_scroll_no_prefetch() -> Result<(), anyhow::Error> {
+    let (sm, mut r) = setup().await?;
+
+    // offset 1000→700: visible indices 14-23, items_above=14 > 10
+    r.scroll_up_collect_with_velocity(300, 0.0).await;
+    assert_eq!(sm.current_selection(), LIVE_SELECTION);
+    assert_eq!(sm.debug_info().peek().prefetch_distance, 0);
+    assert_eq!(sm.debug_info().peek().trigger_threshold, 10);
+
+    Ok(())
+}
+
+/// A fast scroll triggers earlier and enlarges the slide limit by the prefetch distance.
+#[tokio::test]
+async fn test_fast_scroll_triggers_early() -> Result<(), anyhow::Error> {
+    let (sm, mut r) = setup().await?;
+
+    // 1000 px/s * 0.5s = 500px = 10 items → trigger at 20 items above
+    // offset 1000→700: items_above=14 → TRIGGER
+    // cursor = min(23 + 20, 29) = 29 → ts 1099, limit = 10 + 40 + 10 = 60
+    r.scroll_up_collect_with_velocity(300, 1000.0).await;
+    let debug = sm.debug_info().peek();
+    assert_eq!((debug.prefetch_distance, debug.trigger_threshold), (10, 20));
+    assert_eq!(
+        sm.current_selection(),
+        "TRUE AND \"timestamp\" <= 1099 ORDER BY timestamp DESC LIMIT 61"
+    );
+    assert_eq!(timestamps(&sm.visible_set().peek()), (1040..=1099).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+/// Prefetch is capped at one buffer no matter how fast the fling.
+#[tokio::test]
+async fn test_prefetch_capped_at_buffer() -> Result<(), anyhow::Error> {
+    let (sm, mut r) = setup().await?;
+
+    // Cap: prefetch = B = 20, trigger at 30 items above
+    // offset 1000→900: items_above=18 → TRIGGER, limit = 10 + 40 + 20 = 70
+    r.scroll_up_collect_with_velocity(100, 100_000.0).await;
+    assert_eq!(sm.debug_info().peek().prefetch_distance, 20);
+    assert_eq!(
+        sm.current_selection(),
+        "TRUE AND \"timestamp\" <= 1099 ORDER BY timestamp DESC LIMIT 71"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+//! VisibleSetDiff tests
+//!
+//! Verifies that applying each emitted diff to the previous window reproduces the
+//! new window exactly, for the initial load, live inserts and window slides.
+
+mod common;
+
+use ankurah_signals::Peek;
+use ankurah_virtual_scroll::{VisibleSetChange, VisibleSetDiff};
+use common::*;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Apply a diff to a list of ids the way a patch-based renderer would
+fn apply(ids: &mut Vec<EntityId>, diff: &VisibleSetDiff<TestMessageView>) {
+    for change in &diff.changes {
+        match change {
+            VisibleSetChange::Remove { index, ids: removed } => {
+                let drained: Vec<EntityId> = ids.drain(*index..*index + removed.len()).collect();
+                assert_eq!(&drained, removed, "removed ids mismatch");
+            }
+            VisibleSetChange::Move { from, to, id } => {
+                let moved = ids.remove(*from);
+                assert_eq!(moved, *id, "moved id mismatch");
+                ids.insert(*to, moved);
+            }
+            VisibleSetChange::Insert { index, items } => {
+                for (offset, item) in items.iter().enumerate() {
+                    ids.insert(index + offset, item.entity().id());
+                }
+            }
+            VisibleSetChange::Update { index, item } => {
+                assert_eq!(ids[*index], item.entity().id(), "updated id mismatch");
+            }
+        }
+    }
+}
+
+fn ids(vs: &VisibleSet<TestMessageView>) -> Vec<EntityId> {
+    vs.items.iter().map(|item| item.entity().id()).collect()
+}
+
+/// Initial load arrives as a single insert of the whole live window.
+#[tokio::test]
+async fn test_initial_diff_inserts_window() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(
+        &ctx,
+        "true",
+        "timestamp DESC",
+        50,
+        2.0,
+        500,
+    )?);
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set_diff().subscribe(tx);
+    sm.start().await;
+
+    let diff = rx.recv().await.expect("initial diff");
+    assert_eq!(diff.changes.len(), 1);
+    match &diff.changes[0] {
+        VisibleSetChange::Insert { index, items } => {
+            assert_eq!(*index, 0);
+            assert_eq!(items.len(), 30);
+        }
+        other => panic!("expected insert, got {:?}", other),
+    }
+
+    let mut patched = Vec::new();
+    apply(&mut patched, &diff);
+    assert_eq!(patched, ids(&sm.visible_set().peek()));
+
+    Ok(())
+}
+
+/// A backward slide is expressed as an insert of older items plus a removal of newer ones.
+#[tokio::test]
+async fn test_slide_diff_reproduces_window() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..100).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(
+        &ctx,
+        "true",
+        "timestamp DESC",
+        50,
+        2.0,
+        500,
+    )?);
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set_diff().subscribe(tx);
+
+    let mut r = MockRenderer::new(sm.clone(), 500);
+    tokio::spawn({
+        let sm = sm.clone();
+        async move { sm.start().await }
+    });
+    let vs = r.next_render().await?;
+    let mut patched = Vec::new();
+    apply(&mut patched, &rx.recv().await.expect("initial diff"));
+    assert_eq!(patched, ids(&vs));
+
+    // Scroll to the top repeatedly so the window slides backward more than once
+    for _ in 0..6 {
+        r.scroll_up_collect(500).await;
+    }
+
+    while let Ok(diff) = rx.try_recv() {
+        apply(&mut patched, &diff);
+    }
+    let current = sm.visible_set().peek();
+    assert!(timestamps(&current)[0] < 1070, "window should have slid backward");
+    assert_eq!(patched, ids(&current));
+
+    Ok(())
+}
+
+/// A new message in Live mode inserts at the newest edge and evicts the oldest item.
+#[tokio::test]
+async fn test_live_insert_diff() -> Result<(), anyhow::Error> {
+    let ctx = durable_sled_setup().await?;
+    create_messages(&ctx, (0..60).map(|i| (1000 + i, 50))).await?;
+
+    let sm = Arc::new(ScrollManager::<TestMessageView>::new(
+        &ctx,
+        "true",
+        "timestamp DESC",
+        50,
+        2.0,
+        500,
+    )?);
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let _guard = sm.visible_set_diff().subscribe(tx);
+    sm.start().await;
+    let mut patched = Vec::new();
+    apply(&mut patched, &rx.recv().await.expect("initial diff"));
+
+    create_messages(&ctx, [(2000, 50)]).await?;
+
+    let diff = tokio::time::timeout(std::time::Duration::from_millis(500), rx.recv())
+        .await?
+        .expect("live diff");
+    apply(&mut patched, &diff);
+
+    let current = sm.visible_set().peek();
+    assert_eq!(patched, ids(&current));
+    assert_eq!(*timestamps(&current).last().unwrap(), 2000);
+    assert!(diff.changes.iter().any(|c| matches!(c, VisibleSetChange::Insert { index: 29, .. })));
+    assert!(diff.changes.iter().any(|c| matches!(c, VisibleSetChange::Remove { index: 0, .. })));
+
+    Ok(())
+}
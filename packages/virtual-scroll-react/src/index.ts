@@ -0,0 +1,256 @@
+/**
+ * React hook for scroll managers generated by `generate_scroll_manager!` (wasm feature)
+ *
+ * `useVirtualScroll` is the renderer side of the contract: it subscribes to the visible
+ * set, reports the first and last items on screen (with direction and speed) on every
+ * scroll event, keeps the intersection item in place when a slide lands, follows the
+ * live edge while the manager asks for auto-scroll, and reports viewport resizes.
+ *
+ * The types below describe any generated `{Model}ScrollManager` structurally, so
+ * `useVirtualScroll(manager)` infers the item type from the bindings' own `.d.ts`.
+ */
+import { useCallback, useEffect, useLayoutEffect, useMemo, useRef, useState } from 'react'
+import type { RefObject } from 'react'
+
+// =============================================================================
+// Generated binding types
+// =============================================================================
+
+/** Current scroll mode (`{Model}ScrollMode`) */
+export type ScrollMode = 'Live' | 'Backward' | 'Forward'
+
+/** Direction of a slide relative to display order (`{Model}LoadDirection`) */
+export type LoadDirection = 'Backward' | 'Forward'
+
+/** Viewport edge where the newest items are shown (`{Model}LiveEdge`) */
+export type LiveEdge = 'Bottom' | 'Top'
+
+/** Any generated View: only its EntityId is used */
+export interface ScrollItem {
+  readonly id: { toString(): string }
+}
+
+/** Anchor item for scroll stability (`{Model}Intersection`) */
+export interface Intersection {
+  entityId: string
+  index: number
+  direction: LoadDirection
+}
+
+/** Failure reported on the visible set (`{Model}ScrollError`) */
+export interface ScrollError {
+  kind: string
+  message: string
+}
+
+/** Intersection failure and the recovery applied (`{Model}IntersectionFailure`) */
+export interface IntersectionFailure {
+  anchor: string
+  direction: LoadDirection
+  recovery: 'none' | 'retriedWider' | 'nearestNeighbor' | 'jumpedToLive'
+  neighbor?: string
+}
+
+/** One emission of the manager's window (`{Model}VisibleSet`) */
+export interface VisibleSet<Item extends ScrollItem> {
+  readonly items: Item[]
+  readonly updatedIds: string[]
+  readonly movedIds: string[]
+  readonly error: ScrollError | undefined
+  readonly intersectionFailure: IntersectionFailure | undefined
+  readonly intersection: Intersection | null
+  readonly hasMorePreceding: boolean
+  readonly hasMoreFollowing: boolean
+  readonly shouldAutoScroll: boolean
+  readonly loadingPreceding: boolean
+  readonly loadingFollowing: boolean
+  readonly initialLoading: boolean
+}
+
+/** Handle returned by `subscribe` (`{Model}ScrollSubscription`) */
+export interface ScrollSubscription {
+  readonly isActive: boolean
+  unsubscribe(): void
+}
+
+/** `{Model}VisibleSetSignal` */
+export interface VisibleSetSignal<Item extends ScrollItem> {
+  get(): VisibleSet<Item>
+  subscribe(callback: (visibleSet: VisibleSet<Item>) => void): ScrollSubscription
+}
+
+/** The parts of a generated `{Model}ScrollManager` the hook uses */
+export interface ScrollManager<Item extends ScrollItem> {
+  readonly mode: ScrollMode
+  readonly liveEdge: LiveEdge
+  readonly isDisposed: boolean
+  visibleSet(): VisibleSetSignal<Item>
+  onScrollWithVelocity(firstVisible: string, lastVisible: string, scrollingBackward: boolean, velocity: number): void
+  setViewportHeight(viewportHeight: number): Promise<void>
+}
+
+// =============================================================================
+// Hook
+// =============================================================================
+
+/** Attribute the hook uses to find rendered items; put it on each item's outer element */
+export const ITEM_ID_ATTRIBUTE = 'data-item-id'
+
+/** Scroll events within this many pixels of a position the hook set are its own */
+const ADJUSTMENT_TOLERANCE_PX = 1
+
+/** The value to render as `key` and `data-item-id` for an item */
+export function itemId(item: ScrollItem): string {
+  return item.id.toString()
+}
+
+export interface VirtualScroll<Item extends ScrollItem, E extends HTMLElement> {
+  /** Attach to the scroll container, which needs `overflow-y: auto` and a height */
+  containerRef: RefObject<E>
+  /** Items in render order: oldest first, or newest first when the live edge is `Top` */
+  items: Item[]
+  /** The latest visible set: loading flags, `hasMore*`, `error`, ... */
+  visibleSet: VisibleSet<Item>
+  /** The manager's mode as of the latest visible set */
+  mode: ScrollMode
+}
+
+/** Position of an item wrapper within the container's content */
+interface Rendered {
+  id: string
+  top: number
+  height: number
+}
+
+function renderedItems(container: HTMLElement): Rendered[] {
+  const containerTop = container.getBoundingClientRect().top - container.scrollTop
+  return Array.from(container.querySelectorAll(`[${ITEM_ID_ATTRIBUTE}]`)).map((el) => {
+    const rect = el.getBoundingClientRect()
+    return { id: el.getAttribute(ITEM_ID_ATTRIBUTE)!, top: rect.top - containerTop, height: rect.height }
+  })
+}
+
+/**
+ * Render a scroll manager's window
+ *
+ * Each item's outer element needs `data-item-id={itemId(item)}`. The caller owns the
+ * manager: create and `start()` it, and `dispose()` it when done. Unmounting
+ * unsubscribes but doesn't dispose; dispose from an effect declared after this hook, so
+ * the hook's cleanup runs first.
+ *
+ * ```tsx
+ * const { containerRef, items } = useVirtualScroll(manager)
+ * return (
+ *   <div ref={containerRef} style={{ height: 600, overflowY: 'auto' }}>
+ *     {items.map((m) => <div key={itemId(m)} data-item-id={itemId(m)}>{m.text}</div>)}
+ *   </div>
+ * )
+ * ```
+ */
+export function useVirtualScroll<Item extends ScrollItem, E extends HTMLElement = HTMLDivElement>(
+  manager: ScrollManager<Item>,
+): VirtualScroll<Item, E> {
+  const containerRef = useRef<E>(null)
+  const [visibleSet, setVisibleSet] = useState(() => manager.visibleSet().get())
+
+  // Item offsets as last laid out, for anchor correction
+  const snapshot = useRef(new Map<string, number>())
+  // Last user scroll event, and the position the hook last scrolled to
+  const lastScroll = useRef<{ top: number; time: number; backward: boolean } | null>(null)
+  const adjusted = useRef<number | null>(null)
+  // Whether the manager's initial load has finished, as of the latest visible set
+  const started = useRef(false)
+
+  useEffect(() => {
+    const subscription = manager.visibleSet().subscribe(setVisibleSet)
+    return () => subscription.unsubscribe()
+  }, [manager])
+
+  const scrollTo = useCallback((container: E, target: number) => {
+    if (Math.abs(target - container.scrollTop) < ADJUSTMENT_TOLERANCE_PX) return
+    container.scrollTop = target
+    adjusted.current = container.scrollTop
+  }, [])
+
+  // After each render: keep the intersection item in place, or follow the live edge,
+  // then record the new layout for the next update
+  useLayoutEffect(() => {
+    const container = containerRef.current
+    if (!container) return
+    started.current = !visibleSet.initialLoading
+    const items = renderedItems(container)
+    const anchor = visibleSet.intersection
+
+    if (anchor) {
+      const before = snapshot.current.get(anchor.entityId)
+      const after = items.find((item) => item.id === anchor.entityId)
+      if (before !== undefined && after) scrollTo(container, container.scrollTop + after.top - before)
+    } else if (visibleSet.shouldAutoScroll) {
+      scrollTo(container, manager.liveEdge === 'Top' ? 0 : container.scrollHeight - container.clientHeight)
+    }
+    snapshot.current = new Map(items.map((item) => [item.id, item.top]))
+  }, [manager, visibleSet, scrollTo])
+
+  useEffect(() => {
+    const container = containerRef.current
+    if (!container) return
+
+    const onScroll = (event: Event) => {
+      const top = container.scrollTop
+      const previous = lastScroll.current
+      const adjustment = adjusted.current
+      adjusted.current = null
+      if (adjustment !== null && Math.abs(top - adjustment) <= ADJUSTMENT_TOLERANCE_PX) {
+        lastScroll.current = { top, time: event.timeStamp, backward: previous?.backward ?? false }
+        return
+      }
+
+      // A position set by the hook is the baseline for direction, so the first user
+      // scroll after following the live edge is reported correctly
+      const baseline = adjustment ?? previous?.top
+      const backward = baseline === undefined || baseline === top ? previous?.backward ?? false : top < baseline
+      const velocity =
+        previous && event.timeStamp > previous.time
+          ? (Math.abs(top - previous.top) / (event.timeStamp - previous.time)) * 1000
+          : 0
+      lastScroll.current = { top, time: event.timeStamp, backward }
+
+      const viewportBottom = top + container.clientHeight
+      const visible = renderedItems(container)
+        .filter((item) => item.top + item.height > top && item.top < viewportBottom)
+        .map((item) => item.id)
+      if (visible.length === 0) return
+      // Rendered newest first: report in item order
+      if (manager.liveEdge === 'Top') visible.reverse()
+      manager.onScrollWithVelocity(visible[0], visible[visible.length - 1], backward, velocity)
+    }
+
+    // Report viewport changes after the first measurement (the manager was built with it).
+    // Changes seen before `start()` resolves are kept for the next report; a rejection
+    // (the manager closed meanwhile) leaves nothing to resize.
+    let viewportHeight: number | null = null
+    const observer = new ResizeObserver(() => {
+      const height = Math.round(container.clientHeight)
+      if (viewportHeight === null) {
+        viewportHeight = height
+      } else if (height !== viewportHeight && height > 0 && started.current && !manager.isDisposed) {
+        viewportHeight = height
+        manager.setViewportHeight(height).catch(() => {})
+      }
+    })
+
+    container.addEventListener('scroll', onScroll, { passive: true })
+    observer.observe(container)
+    return () => {
+      observer.disconnect()
+      container.removeEventListener('scroll', onScroll)
+    }
+  }, [manager])
+
+  const items = useMemo(() => {
+    const items = visibleSet.items
+    return manager.liveEdge === 'Top' ? items.reverse() : items
+  }, [manager, visibleSet])
+
+  return { containerRef, items, visibleSet, mode: manager.mode }
+}
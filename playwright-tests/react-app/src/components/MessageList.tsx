@@ -16,12 +16,36 @@ interface MessageView {
   room: string
 }
 
+interface MessageIntersection {
+  entityId: string
+  index: number
+  direction: 'Backward' | 'Forward'
+}
+
+interface MessageScrollError {
+  kind: string
+  message: string
+}
+
 interface MessageVisibleSet {
   items: MessageView[]
-  intersection: () => { entityId: string; index: number } | null
+  intersection: () => MessageIntersection | null
   hasMorePreceding: () => boolean
   hasMoreFollowing: () => boolean
   shouldAutoScroll: () => boolean
+  error: MessageScrollError | undefined
+}
+
+interface MessageScrollDebugInfo {
+  itemsAbove: number
+  itemsBelow: number
+  triggerThreshold: number
+  firstVisibleIndex: number
+  lastVisibleIndex: number
+  updateCount: number
+  updatePending: boolean
+  prefetchDistance: number
+  evictedItems: number
 }
 
 interface MessageVisibleSetSignal {
@@ -32,8 +56,12 @@ interface MessageScrollManager {
   start: () => Promise<void>
   onScroll: (firstVisible: string, lastVisible: string, scrollingBackward: boolean) => void
   visibleSet: () => MessageVisibleSetSignal
-  mode: string
+  debugInfo: () => { get: () => MessageScrollDebugInfo }
+  mode: 'Live' | 'Backward' | 'Forward'
   currentSelection: () => string
+  jumpToLive: () => Promise<void>
+  setViewportHeight: (viewportHeight: number) => Promise<void>
+  setPredicate: (predicate: string) => Promise<void>
 }
 
 interface WasmBindings {
@@ -198,12 +226,12 @@ export function MessageList() {
     if (!manager) return
     const vs = manager.visibleSet().get()
     setItems([...vs.items])
-    const inter = vs.intersection()
+    const inter = vs.intersection
     setIntersection(inter ? { entityId: inter.entityId, index: inter.index } : null)
     setMode(manager.mode)
-    setHasMorePreceding(vs.hasMorePreceding())
-    setHasMoreFollowing(vs.hasMoreFollowing())
-    setShouldAutoScroll(vs.shouldAutoScroll())
+    setHasMorePreceding(vs.hasMorePreceding)
+    setHasMoreFollowing(vs.hasMoreFollowing)
+    setShouldAutoScroll(vs.shouldAutoScroll)
   }, [])
 
   const findVisibleItems = useCallback((): { firstId: string; lastId: string } | null => {
@@ -293,12 +321,12 @@ export function MessageList() {
 
         const vs = manager.visibleSet().get()
         setItems([...vs.items])
-        const inter = vs.intersection()
+        const inter = vs.intersection
         setIntersection(inter ? { entityId: inter.entityId, index: inter.index } : null)
         setMode(manager.mode)
-        setHasMorePreceding(vs.hasMorePreceding())
-        setHasMoreFollowing(vs.hasMoreFollowing())
-        setShouldAutoScroll(vs.shouldAutoScroll())
+        setHasMorePreceding(vs.hasMorePreceding)
+        setHasMoreFollowing(vs.hasMoreFollowing)
+        setShouldAutoScroll(vs.shouldAutoScroll)
 
         scrollManagerRef.current = manager
         setScrollManager(manager)
@@ -318,12 +346,13 @@ export function MessageList() {
       },
       jumpToLive: async () => {
         testModeDisableAutoScroll.current = false  // Re-enable auto-scroll in live mode
+        await scrollManagerRef.current?.jumpToLive()
         if (containerRef.current) {
           containerRef.current.scrollTop = containerRef.current.scrollHeight
         }
       },
-      updateFilter: async () => {
-        console.warn('updateFilter is not supported in the new ScrollManager API')
+      updateFilter: async (predicate) => {
+        await scrollManagerRef.current?.setPredicate(predicate)
       },
 
       // Scroll control
@@ -361,17 +390,17 @@ export function MessageList() {
       getIntersection: () => {
         const manager = scrollManagerRef.current
         if (manager) {
-          const inter = manager.visibleSet().get().intersection()
+          const inter = manager.visibleSet().get().intersection
           return inter ? { entityId: inter.entityId, index: inter.index } : null
         }
         return intersection
       },
       getMode: () => scrollManagerRef.current?.mode ?? mode,
-      hasMorePreceding: () => scrollManagerRef.current?.visibleSet().get().hasMorePreceding() ?? hasMorePreceding,
-      hasMoreFollowing: () => scrollManagerRef.current?.visibleSet().get().hasMoreFollowing() ?? hasMoreFollowing,
-      hasMoreOlder: () => scrollManagerRef.current?.visibleSet().get().hasMorePreceding() ?? hasMorePreceding,
-      hasMoreNewer: () => scrollManagerRef.current?.visibleSet().get().hasMoreFollowing() ?? hasMoreFollowing,
-      shouldAutoScroll: () => scrollManagerRef.current?.visibleSet().get().shouldAutoScroll() ?? shouldAutoScroll,
+      hasMorePreceding: () => scrollManagerRef.current?.visibleSet().get().hasMorePreceding ?? hasMorePreceding,
+      hasMoreFollowing: () => scrollManagerRef.current?.visibleSet().get().hasMoreFollowing ?? hasMoreFollowing,
+      hasMoreOlder: () => scrollManagerRef.current?.visibleSet().get().hasMorePreceding ?? hasMorePreceding,
+      hasMoreNewer: () => scrollManagerRef.current?.visibleSet().get().hasMoreFollowing ?? hasMoreFollowing,
+      shouldAutoScroll: () => scrollManagerRef.current?.visibleSet().get().shouldAutoScroll ?? shouldAutoScroll,
       isLoading: () => false,
       getItemCount: () => scrollManagerRef.current?.visibleSet().get().items.length ?? items.length,
       getCurrentSelection: () => scrollManagerRef.current?.currentSelection() ?? '',
@@ -2,14 +2,24 @@ import { defineConfig } from 'vite'
 import react from '@vitejs/plugin-react'
 import wasm from 'vite-plugin-wasm'
 import topLevelAwait from 'vite-plugin-top-level-await'
+import { fileURLToPath } from 'node:url'
 
 export default defineConfig({
   plugins: [react(), wasm(), topLevelAwait()],
+  resolve: {
+    // Use the hook package from source, with this app's React
+    alias: {
+      '@ankurah/virtual-scroll-react': fileURLToPath(
+        new URL('../../packages/virtual-scroll-react/src/index.ts', import.meta.url)
+      ),
+    },
+    dedupe: ['react'],
+  },
   server: {
     port: 5199,
     fs: {
       // Allow serving files from parent directories
-      allow: ['..'],
+      allow: ['..', '../../packages'],
     },
   },
   build: {
@@ -0,0 +1,30 @@
+//! UniFFI bindings for ankurah-virtual-scroll
+//!
+//! Compiles the `uniffi` output of `generate_scroll_manager!` so the generated records,
+//! enums and objects are checked by UniFFI's scaffolding macros. This is its own
+//! workspace: in the main one, feature unification would also turn on ankurah's `wasm`
+//! derive output (from the playwright bindings), which doesn't build alongside `uniffi`.
+//!
+//! Run with `cargo test --manifest-path uniffi-bindings/Cargo.toml`.
+
+// ankurah's uniffi Model derive gives the result set and livequery `len()` only
+#![allow(clippy::len_without_is_empty)]
+
+use ankurah::Model;
+use serde::{Deserialize, Serialize};
+
+uniffi::setup_scaffolding!();
+
+/// Chat message ordered by timestamp
+#[derive(Model, Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    pub text: String,
+    pub timestamp: i64,
+}
+
+ankurah_virtual_scroll::generate_scroll_manager!(
+    Message,
+    MessageView,
+    MessageLiveQuery,
+    timestamp_field = "timestamp"
+);
@@ -0,0 +1,151 @@
+//! Generated UniFFI wrapper tests
+//!
+//! Calls the generated objects from Rust, as the scaffolding does for a foreign host.
+//! Standard configuration: 50px rows, 500px viewport (S = 10, live window = 30).
+
+use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+use std::sync::Arc;
+use std::time::Duration;
+
+use ankurah::policy::DEFAULT_CONTEXT;
+use ankurah::{Context, Node, PermissiveAgent, View};
+use ankurah_storage_sled::SledStorageEngine;
+use virtual_scroll_uniffi_bindings::*;
+
+fn options(intersection_recovery: Option<&str>) -> MessageScrollOptions {
+    MessageScrollOptions {
+        order_by: None,
+        viewport_height: 500,
+        minimum_row_height: 50,
+        predicate: None,
+        buffer_factor: None,
+        buffer_screens: None,
+        live_buffer_screens: None,
+        trigger_screens: None,
+        debounce_items: None,
+        max_window_size: None,
+        prefetch_seconds: None,
+        intersection_recovery: intersection_recovery.map(str::to_string),
+        start_at: None,
+    }
+}
+
+async fn setup(count: i64, intersection_recovery: Option<&str>) -> Result<(Context, Arc<MessageScrollManager>), anyhow::Error> {
+    setup_with_options(count, options(intersection_recovery)).await
+}
+
+async fn setup_with_options(
+    count: i64,
+    options: MessageScrollOptions,
+) -> Result<(Context, Arc<MessageScrollManager>), anyhow::Error> {
+    let node = Node::new_durable(Arc::new(SledStorageEngine::new_test()?), PermissiveAgent::new());
+    node.system.create().await?;
+    let ctx = node.context_async(DEFAULT_CONTEXT).await;
+
+    let trx = ctx.begin();
+    for i in 0..count {
+        trx.create(&Message { text: format!("message {}", i), timestamp: 1000 + i }).await?;
+    }
+    trx.commit().await?;
+
+    let manager = MessageScrollManager::with_options(&ctx, options)?;
+    Ok((ctx, manager))
+}
+
+fn ids(visible_set: &MessageVisibleSet) -> Vec<String> {
+    visible_set.items().iter().map(|item| item.id().to_string()).collect()
+}
+
+struct Counter(Arc<AtomicUsize>);
+
+impl MessageVisibleSetCallback for Counter {
+    fn on_change(&self, _value: Arc<MessageVisibleSet>) {
+        self.0.fetch_add(1, SeqCst);
+    }
+}
+
+/// subscribe() replays the current value, and callbacks stop once the handle is
+/// unsubscribed.
+#[tokio::test]
+async fn test_visible_set_subscription() -> Result<(), anyhow::Error> {
+    let (_ctx, manager) = setup(100, None).await?;
+    manager.clone().start().await;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let subscription = manager.clone().visible_set().subscribe(Box::new(Counter(calls.clone())));
+    assert_eq!(calls.load(SeqCst), 1);
+    assert!(subscription.is_active());
+
+    let window = ids(&manager.clone().visible_set().get());
+    assert_eq!(window.len(), 30);
+    manager.on_scroll(window[10].clone(), window[19].clone(), true);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let before = calls.load(SeqCst);
+    assert!(before > 1, "slide should emit new visible sets");
+
+    subscription.unsubscribe();
+    assert!(!subscription.is_active());
+    let window = ids(&manager.clone().visible_set().get());
+    manager.on_scroll(window[0].clone(), window[9].clone(), true);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(calls.load(SeqCst), before);
+
+    Ok(())
+}
+
+/// Fallible methods throw MessageScrollError; failures on the visible set are plain
+/// MessageScrollErrorInfo values, alongside the MessageIntersectionFailure record.
+#[tokio::test]
+async fn test_errors() -> Result<(), anyhow::Error> {
+    let (ctx, manager) = setup(100, Some("report")).await?;
+
+    let result = manager.clone().jump_to_live().await;
+    assert!(matches!(result, Err(MessageScrollError::InvalidState { .. })), "{:?}", result);
+
+    manager.clone().start().await;
+    let visible_set = manager.clone().visible_set().get();
+    assert!(visible_set.error().is_none());
+
+    // Re-sort the anchor of an in-flight backward slide out of its result
+    let items = visible_set.items();
+    let anchor = items[19].clone();
+    manager.on_scroll(items[10].id().to_string(), anchor.id().to_string(), true);
+    let trx = ctx.begin();
+    trx.edit::<Message>(anchor.entity())?.timestamp().set(&2000)?;
+    trx.commit().await?;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let visible_set = manager.clone().visible_set().get();
+    match visible_set.error() {
+        Some(MessageScrollErrorInfo::IntersectionFailed { anchor: failed, direction }) => {
+            assert_eq!(failed, anchor.id().to_string());
+            assert_eq!(direction, MessageLoadDirection::Backward);
+        }
+        other => panic!("expected IntersectionFailed, got {:?}", other),
+    }
+    let failure = visible_set.intersection_failure().expect("intersection failure");
+    assert_eq!(failure.anchor, anchor.id().to_string());
+    assert_eq!(failure.direction, MessageLoadDirection::Backward);
+    assert_eq!(failure.recovery, MessageRecoveryAction::None);
+
+    Ok(())
+}
+
+/// The buffer options reach the config: `live_buffer_screens` sizes the live window
+/// (overriding `buffer_factor`), and `buffer_screens` is validated against the trigger.
+#[tokio::test]
+async fn test_buffer_options() -> Result<(), anyhow::Error> {
+    let (_ctx, manager) = setup_with_options(
+        100,
+        MessageScrollOptions { buffer_factor: Some(3.0), live_buffer_screens: Some(4.0), ..options(None) },
+    )
+    .await?;
+    manager.clone().start().await;
+    // S + 4 screens (the buffer factor alone would give 40)
+    assert_eq!(manager.clone().visible_set().get().items().len(), 50);
+
+    let result = setup_with_options(100, MessageScrollOptions { buffer_screens: Some(0.5), ..options(None) }).await;
+    assert!(result.is_err(), "buffer_screens below trigger_screens should be rejected");
+
+    Ok(())
+}